@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use crate::input::InputResolver;
+use crate::registry::Entry;
+use crate::solution::Part;
+
+/// default location of the answers database
+pub fn answers_path() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml"))
+}
+
+#[derive(Error, Debug)]
+pub enum AnswersError {
+    #[error("failed to read answers at {path:?}: {source}")]
+    Io { path: PathBuf, source: std::io::Error },
+    #[error("invalid answers toml: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("invalid answers key {key:?}, expected {expected}")]
+    InvalidKey { key: String, expected: &'static str },
+    #[error("invalid answer for {key:?}, expected a string or integer")]
+    InvalidValue { key: String },
+}
+
+/// accepted answers keyed by year, day and part
+///
+/// ```toml
+/// [2024.day1]
+/// part1 = "1646452"
+/// part2 = 23609874
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32, Part), String>,
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AnswersError> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|source| AnswersError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&contents)
+    }
+    pub fn parse(contents: &str) -> Result<Self, AnswersError> {
+        let table = contents.parse::<toml::Table>()?;
+        let mut answers = Self::default();
+        for (year_key, days) in table {
+            let year = parse_key(&year_key, "", "a year like \"2024\"")?;
+            let Some(days) = days.as_table() else {
+                return Err(AnswersError::InvalidKey { key: year_key, expected: "a table of days" });
+            };
+            for (day_key, parts) in days {
+                let day = parse_key(day_key, "day", "a day like \"day1\"")?;
+                let Some(parts) = parts.as_table() else {
+                    return Err(AnswersError::InvalidKey { key: day_key.clone(), expected: "a table of parts" });
+                };
+                for (part_key, answer) in parts {
+                    let part = match part_key.as_str() {
+                        "part1" => Part::One,
+                        "part2" => Part::Two,
+                        _ => return Err(AnswersError::InvalidKey { key: part_key.clone(), expected: "part1 or part2" }),
+                    };
+                    let answer = match answer {
+                        toml::Value::String(answer) => answer.clone(),
+                        toml::Value::Integer(answer) => answer.to_string(),
+                        _ => return Err(AnswersError::InvalidValue { key: format!("{year_key}.{day_key}.{part_key}") }),
+                    };
+                    answers.insert(year, day, part, answer);
+                }
+            }
+        }
+        Ok(answers)
+    }
+    pub fn get(&self, year: u32, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+    pub fn insert(&mut self, year: u32, day: u32, part: Part, answer: impl Into<String>) {
+        self.answers.insert((year, day, part), answer.into());
+    }
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+fn parse_key(key: &str, prefix: &str, expected: &'static str) -> Result<u32, AnswersError> {
+    key.strip_prefix(prefix)
+        .and_then(|number| number.parse().ok())
+        .ok_or_else(|| AnswersError::InvalidKey { key: key.to_string(), expected })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    /// no answer is recorded, the solution is not run
+    Missing,
+    Error(String),
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Fail { .. } | Self::Error(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected, actual } => write!(f, "FAIL expected {expected}, got {actual}"),
+            Self::Missing => write!(f, "missing"),
+            Self::Error(err) => write!(f, "ERROR {err}"),
+        }
+    }
+}
+
+/// checks a single entry against the recorded answer for its day and part
+pub fn verify_entry(entry: &Entry, answers: &Answers, resolver: &InputResolver) -> Verdict {
+    let Some(expected) = answers.get(resolver.year(), entry.day(), entry.part()) else {
+        return Verdict::Missing;
+    };
+    let actual = resolver
+        .load(entry.day(), None)
+        .map_err(anyhow::Error::from)
+        .and_then(|input| entry.run(&input));
+    match actual {
+        Ok(actual) if actual.as_str() == expected => Verdict::Pass,
+        Ok(actual) => Verdict::Fail {
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        },
+        Err(err) => Verdict::Error(format!("{err:#}")),
+    }
+}
+
+/// checks each entry, i.e. everything in [`crate::registry::Registry::entries`] including variants
+pub fn verify<'a>(
+    entries: impl IntoIterator<Item = &'a Entry>,
+    answers: &Answers,
+    resolver: &InputResolver,
+) -> Vec<(&'a Entry, Verdict)> {
+    entries
+        .into_iter()
+        .map(|entry| (entry, verify_entry(entry, answers, resolver)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::Registry;
+    use crate::solution::{Answer, Solution};
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 1;
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.split_whitespace().map(|item| item.parse()).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok(input.iter().product::<u32>().into())
+        }
+    }
+
+    #[test]
+    fn parse_test() -> anyhow::Result<()> {
+        let answers = Answers::parse(r#"
+            [2024.day1]
+            part1 = "1646452"
+            part2 = 23609874
+
+            [2023.day18]
+            part1 = "56,29"
+        "#)?;
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.get(2024, 1, Part::One), Some("1646452"));
+        assert_eq!(answers.get(2024, 1, Part::Two), Some("23609874"));
+        assert_eq!(answers.get(2023, 18, Part::One), Some("56,29"));
+        assert_eq!(answers.get(2023, 18, Part::Two), None);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid_test() {
+        assert!(matches!(Answers::parse("[2024.one]\npart1 = 1"), Err(AnswersError::InvalidKey { .. })));
+        assert!(matches!(Answers::parse("[2024.day1]\npart3 = 1"), Err(AnswersError::InvalidKey { .. })));
+        assert!(matches!(Answers::parse("[2024.day1]\npart1 = 1.5"), Err(AnswersError::InvalidValue { .. })));
+        assert!(matches!(Answers::parse("[2024.day1"), Err(AnswersError::Toml(_))));
+    }
+
+    #[test]
+    fn verify_test() -> anyhow::Result<()> {
+        let root = std::env::temp_dir().join(format!("advent_of_code_answers_{}", std::process::id()));
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("day1"), "2 3 4")?;
+        let resolver = InputResolver::new(&root).with_year(2024);
+        let registry = Registry::with(|registry| {
+            registry.register::<Sum>(Part::One).register::<Sum>(Part::Two).register_variant::<Sum>(Part::Two, "again");
+        });
+
+        let mut answers = Answers::default();
+        answers.insert(2024, 1, Part::One, "9");
+        answers.insert(2024, 1, Part::Two, "25");
+        let verdicts = verify(registry.entries(), &answers, &resolver)
+            .into_iter()
+            .map(|(entry, verdict)| (entry.name(), verdict))
+            .collect::<Vec<_>>();
+        assert_eq!(verdicts, vec![
+            ("day1p1".to_string(), Verdict::Pass),
+            ("day1p2".to_string(), Verdict::Fail { expected: "25".to_string(), actual: "24".to_string() }),
+            ("day1p2_again".to_string(), Verdict::Fail { expected: "25".to_string(), actual: "24".to_string() }),
+        ]);
+
+        let verdicts = verify(registry.entries(), &Answers::default(), &resolver);
+        assert!(verdicts.iter().all(|(_, verdict)| verdict == &Verdict::Missing));
+
+        let verdict = verify_entry(registry.get(1, Part::One).unwrap(), &answers, &resolver.with_year(2023));
+        assert_eq!(verdict, Verdict::Missing);
+
+        fs::remove_dir_all(root)?;
+        Ok(())
+    }
+}
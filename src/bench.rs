@@ -0,0 +1,151 @@
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use crate::registry::Entry;
+
+/// min/median/max of a set of samples, serialized as nanoseconds
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "as_nanos")]
+    pub max: Duration,
+}
+
+fn as_nanos<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+impl Stats {
+    /// `None` when there are no samples
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Some(Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} / {:?} / {:?}", self.min, self.median, self.max)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BenchResult {
+    pub name: String,
+    pub day: u32,
+    pub part: u32,
+    pub variant: Option<&'static str>,
+    pub iterations: usize,
+    pub answer: String,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Display for BenchResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} (parse {}, solve {}, min/median/max of {})",
+            self.name, self.answer, self.parse, self.solve, self.iterations,
+        )
+    }
+}
+
+/// times parsing and solving `input` separately, `iterations` times each
+pub fn bench_entry(entry: &Entry, input: &str, iterations: usize) -> anyhow::Result<BenchResult> {
+    let iterations = iterations.max(1);
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut solve_samples = Vec::with_capacity(iterations);
+    let mut answer = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = entry.parse(input)?;
+        parse_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        let solved = entry.solve(&parsed)?;
+        solve_samples.push(start.elapsed());
+        answer = Some(solved);
+    }
+    Ok(BenchResult {
+        name: entry.name(),
+        day: entry.day(),
+        part: entry.part().number(),
+        variant: entry.variant(),
+        iterations,
+        answer: answer.map(|answer| answer.to_string()).unwrap_or_default(),
+        parse: Stats::from_samples(parse_samples).expect("at least one iteration"),
+        solve: Stats::from_samples(solve_samples).expect("at least one iteration"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::Registry;
+    use crate::solution::{Answer, Part, Solution};
+    use super::*;
+
+    struct Count;
+
+    impl Solution for Count {
+        const DAY: u32 = 3;
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok(input.len().into())
+        }
+    }
+
+    #[test]
+    fn stats_test() {
+        let ms = Duration::from_millis;
+        assert_eq!(Stats::from_samples(vec![]), None);
+        assert_eq!(
+            Stats::from_samples(vec![ms(5), ms(1), ms(3)]),
+            Some(Stats { min: ms(1), median: ms(3), max: ms(5) }),
+        );
+        assert_eq!(
+            Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(8)]),
+            Some(Stats { min: ms(1), median: ms(3), max: ms(8) }),
+        );
+    }
+
+    #[test]
+    fn bench_entry_test() -> anyhow::Result<()> {
+        let registry = Registry::with(|registry| {
+            registry.register_variant::<Count>(Part::One, "lines");
+        });
+        let entry = registry.entries().next().unwrap();
+        let result = bench_entry(entry, "a\nb\nc", 3)?;
+        assert_eq!(result.name, "day3p1_lines");
+        assert_eq!(result.answer, "3");
+        assert_eq!(result.iterations, 3);
+        assert!(result.parse.min <= result.parse.median && result.parse.median <= result.parse.max);
+
+        let json = serde_json::to_value(&result)?;
+        assert_eq!(json["part"], 1);
+        assert_eq!(json["variant"], "lines");
+        assert!(json["solve"]["median_ns"].is_u64());
+        Ok(())
+    }
+}
@@ -0,0 +1,328 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Duration;
+use anyhow::bail;
+use clap::{Args, Parser, Subcommand};
+use advent_of_code::answers::{answers_path, verify, Answers, Verdict};
+use advent_of_code::bench::bench_entry;
+use advent_of_code::client::{ClientConfig, PuzzleClient, SubmitOutcome};
+use advent_of_code::fixtures::{fixtures_dir, PuzzleExamples};
+use advent_of_code::input::{InputResolver, InputSource};
+use advent_of_code::logging::init_tracing;
+use advent_of_code::registry::Registry;
+use advent_of_code::runner::{run_all, RunOptions};
+use advent_of_code::scaffold::{bin_dir, scaffold_day};
+use advent_of_code::solution::Part;
+
+mod solutions;
+
+/// runs any registered day/part against its input
+#[derive(Debug, Parser)]
+#[command(name = "aoc", args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// runs a day, same as passing `--day` without a subcommand
+    Run(RunArgs),
+    /// checks every solution against the recorded answers
+    Verify(VerifyArgs),
+    /// times parse and solve for each solution over several iterations
+    Bench(BenchArgs),
+    /// runs every solution concurrently and prints a summary table
+    All(AllArgs),
+    /// downloads a day's input into the inputs dir unless it's already there, uses AOC_SESSION and AOC_BASE_URL
+    Fetch(FetchArgs),
+    /// submits an answer, solving the part first when `--answer` is omitted
+    Submit(SubmitArgs),
+    /// extracts the examples and answers from a saved puzzle page into the fixtures dir
+    Examples(ExamplesArgs),
+    /// generates `src/bin/day{N}p1.rs` and `src/bin/day{N}p2.rs` from the template and adds them to the runner
+    New(NewArgs),
+}
+
+#[derive(Debug, Args)]
+struct YearArgs {
+    /// event year used to resolve `inputs/<year>/day{N}`
+    #[arg(long)]
+    year: Option<u32>,
+}
+
+impl YearArgs {
+    fn resolver(&self) -> InputResolver {
+        let resolver = InputResolver::from_env();
+        match self.year {
+            Some(year) => resolver.with_year(year),
+            None => resolver,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// day to run
+    #[arg(long, required = true)]
+    day: Option<u32>,
+    /// part to run, runs every registered part for the day when omitted
+    #[arg(long)]
+    part: Option<Part>,
+    /// input file to use instead of `inputs/day{N}`, `-` reads from stdin
+    #[arg(long, conflicts_with = "name")]
+    input: Option<InputSource>,
+    /// alternate named input, i.e. `example2` for `inputs/day{N}.example2`
+    #[arg(long)]
+    name: Option<String>,
+    #[command(flatten)]
+    year: YearArgs,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// only verify this day
+    #[arg(long)]
+    day: Option<u32>,
+    /// answers file to check against
+    #[arg(long, default_value_os_t = answers_path())]
+    answers: PathBuf,
+    #[command(flatten)]
+    year: YearArgs,
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    /// only bench this day
+    #[arg(long)]
+    day: Option<u32>,
+    /// only bench this part
+    #[arg(long)]
+    part: Option<Part>,
+    /// runs of parse and solve per solution
+    #[arg(long, short = 'n', default_value_t = 10)]
+    iterations: usize,
+    /// prints the results as json instead of one line per solution
+    #[arg(long)]
+    json: bool,
+    #[command(flatten)]
+    year: YearArgs,
+}
+
+#[derive(Debug, Args)]
+struct AllArgs {
+    /// worker threads, defaults to the available parallelism
+    #[arg(long, short = 'j')]
+    jobs: Option<usize>,
+    /// seconds before a solution is reported as timed out
+    #[arg(long, default_value_t = 30)]
+    timeout: u64,
+    #[command(flatten)]
+    year: YearArgs,
+}
+
+#[derive(Debug, Args)]
+struct FetchArgs {
+    /// day to download
+    #[arg(long)]
+    day: u32,
+    #[command(flatten)]
+    year: YearArgs,
+}
+
+#[derive(Debug, Args)]
+struct SubmitArgs {
+    /// day to submit
+    #[arg(long)]
+    day: u32,
+    /// part to submit
+    #[arg(long)]
+    part: Part,
+    /// answer to submit instead of running the solution
+    #[arg(long)]
+    answer: Option<String>,
+    #[command(flatten)]
+    year: YearArgs,
+}
+
+#[derive(Debug, Args)]
+struct ExamplesArgs {
+    /// day the page belongs to
+    #[arg(long)]
+    day: u32,
+    /// saved puzzle description html
+    #[arg(long)]
+    html: PathBuf,
+    /// directory to write `day{N}/example{I}` and `day{N}/answers` into
+    #[arg(long, default_value_os_t = fixtures_dir())]
+    fixtures: PathBuf,
+}
+
+#[derive(Debug, Args)]
+struct NewArgs {
+    /// day to generate
+    #[arg(long)]
+    day: u32,
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    let cli = Cli::parse();
+    init_tracing();
+    let registry = Registry::with(solutions::register_all);
+    match cli.command {
+        Some(Command::Run(args)) => run(&registry, args),
+        Some(Command::Verify(args)) => run_verify(&registry, args),
+        Some(Command::Bench(args)) => {
+            let registry = Registry::with(|registry| {
+                solutions::register_all(registry);
+                solutions::register_attempts(registry);
+            });
+            run_bench(&registry, args)
+        }
+        Some(Command::All(args)) => run_all_days(registry, args),
+        Some(Command::Fetch(args)) => run_fetch(args),
+        Some(Command::Submit(args)) => run_submit(&registry, args),
+        Some(Command::Examples(args)) => run_examples(args),
+        Some(Command::New(args)) => run_new(args),
+        None => run(&registry, cli.run),
+    }
+}
+
+fn run(registry: &Registry, args: RunArgs) -> anyhow::Result<ExitCode> {
+    let Some(day) = args.day else {
+        bail!("--day is required");
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let entries = parts
+        .into_iter()
+        .filter_map(|part| registry.get(day, part))
+        .collect::<Vec<_>>();
+    if entries.is_empty() {
+        match args.part {
+            Some(part) => bail!("no solution registered for day {day} part {part}"),
+            None => bail!("no solution registered for day {day}"),
+        }
+    }
+    let input = match &args.input {
+        Some(source) => source.load(day)?,
+        None => args.year.resolver().load(day, args.name.as_deref())?,
+    };
+    for entry in entries {
+        let answer = entry.run(&input)?;
+        println!("{entry}: {answer}");
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_verify(registry: &Registry, args: VerifyArgs) -> anyhow::Result<ExitCode> {
+    let answers = Answers::load(&args.answers)?;
+    let entries = registry
+        .entries()
+        .filter(|entry| args.day.is_none_or(|day| entry.day() == day));
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for (entry, verdict) in verify(entries, &answers, &args.year.resolver()) {
+        println!("{entry}: {verdict}");
+        match verdict {
+            Verdict::Pass => passed += 1,
+            Verdict::Missing => missing += 1,
+            Verdict::Fail { .. } | Verdict::Error(_) => failed += 1,
+        }
+    }
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    Ok(if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn run_bench(registry: &Registry, args: BenchArgs) -> anyhow::Result<ExitCode> {
+    let resolver = args.year.resolver();
+    let entries = registry
+        .entries()
+        .filter(|entry| args.day.is_none_or(|day| entry.day() == day))
+        .filter(|entry| args.part.is_none_or(|part| entry.part() == part));
+    let mut results = vec![];
+    let mut failed = 0;
+    for entry in entries {
+        let result = resolver
+            .load(entry.day(), None)
+            .map_err(anyhow::Error::from)
+            .and_then(|input| bench_entry(entry, &input, args.iterations));
+        match result {
+            Ok(result) => {
+                if !args.json {
+                    println!("{result}");
+                }
+                results.push(result);
+            }
+            Err(err) => {
+                failed += 1;
+                eprintln!("{entry}: {err:#}");
+            }
+        }
+    }
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    }
+    Ok(if failed > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn run_all_days(registry: Registry, args: AllArgs) -> anyhow::Result<ExitCode> {
+    let options = RunOptions {
+        jobs: args.jobs,
+        timeout: Some(Duration::from_secs(args.timeout)),
+    };
+    let report = run_all(Arc::new(registry), args.year.resolver(), options);
+    println!("{report}");
+    Ok(if report.failed() > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn run_fetch(args: FetchArgs) -> anyhow::Result<ExitCode> {
+    let resolver = args.year.resolver();
+    let client = PuzzleClient::new(ClientConfig::from_env(&resolver)?);
+    let input = client.load_or_fetch_input(&resolver, args.day)?;
+    println!("day {}: {} lines in {:?}", args.day, input.lines().count(), resolver.resolve(args.day, None));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_submit(registry: &Registry, args: SubmitArgs) -> anyhow::Result<ExitCode> {
+    let resolver = args.year.resolver();
+    let client = PuzzleClient::new(ClientConfig::from_env(&resolver)?);
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let Some(entry) = registry.get(args.day, args.part) else {
+                bail!("no solution registered for day {} part {}", args.day, args.part);
+            };
+            entry.run(&client.load_or_fetch_input(&resolver, args.day)?)?.to_string()
+        }
+    };
+    let outcome = client.submit(args.day, args.part, &answer)?;
+    println!("day {} part {}: {answer} {outcome}", args.day, args.part);
+    Ok(if outcome == SubmitOutcome::Correct { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+fn run_examples(args: ExamplesArgs) -> anyhow::Result<ExitCode> {
+    let html = fs::read_to_string(&args.html)?;
+    let examples = PuzzleExamples::from_html(&html);
+    let dir = examples.write(&args.fixtures, args.day)?;
+    println!(
+        "day {}: {} examples, {} answers in {dir:?}",
+        args.day,
+        examples.examples.len(),
+        examples.answers.len(),
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+fn run_new(args: NewArgs) -> anyhow::Result<ExitCode> {
+    for path in scaffold_day(bin_dir(), args.day)? {
+        println!("created {path:?}");
+    }
+    println!("run them with `cargo run --bin day{0}p1` and `cargo run --bin day{0}p2`", args.day);
+    Ok(ExitCode::SUCCESS)
+}
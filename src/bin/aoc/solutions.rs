@@ -0,0 +1,123 @@
+//! pulls every `src/bin/dayNpM.rs`, and the `incomplete_attempts` worth benchmarking, into the runner so they can be executed in-process.
+//!
+//! the day binaries keep their own `main` for standalone use, so those are unused here.
+//! the incomplete attempts are left out of tests, their own tests were left broken.
+#![allow(dead_code, unused)]
+
+use advent_of_code::registry::Registry;
+
+#[path = "../day1p1.rs"]
+mod day1p1;
+#[path = "../day1p2.rs"]
+mod day1p2;
+#[path = "../day2p1.rs"]
+mod day2p1;
+#[path = "../day2p2.rs"]
+mod day2p2;
+#[path = "../day2p2_optimized.rs"]
+mod day2p2_optimized;
+#[path = "../day3p1.rs"]
+mod day3p1;
+#[path = "../day3p2.rs"]
+mod day3p2;
+#[path = "../day4p1.rs"]
+mod day4p1;
+#[cfg(not(test))]
+#[path = "../incomplete_attempts/day4p1a.rs"]
+mod day4p1a;
+#[cfg(not(test))]
+#[path = "../incomplete_attempts/day4p1c.rs"]
+mod day4p1c;
+#[path = "../day4p2.rs"]
+mod day4p2;
+#[path = "../day5p1.rs"]
+mod day5p1;
+#[path = "../day5p2.rs"]
+mod day5p2;
+#[path = "../day6p1.rs"]
+mod day6p1;
+#[path = "../day6p2.rs"]
+mod day6p2;
+#[path = "../day7p1.rs"]
+mod day7p1;
+#[path = "../day7p2.rs"]
+mod day7p2;
+#[path = "../day8p1.rs"]
+mod day8p1;
+#[path = "../day8p2.rs"]
+mod day8p2;
+#[path = "../day9p1.rs"]
+mod day9p1;
+#[path = "../day9p2.rs"]
+mod day9p2;
+#[path = "../day10p1.rs"]
+mod day10p1;
+#[path = "../day10p2.rs"]
+mod day10p2;
+#[path = "../day11p1.rs"]
+mod day11p1;
+#[path = "../day11p2.rs"]
+mod day11p2;
+#[path = "../day12p1.rs"]
+mod day12p1;
+#[path = "../day12p2.rs"]
+mod day12p2;
+#[path = "../day13p1.rs"]
+mod day13p1;
+#[path = "../day15p1.rs"]
+mod day15p1;
+#[path = "../day15p2.rs"]
+mod day15p2;
+#[path = "../day18p1.rs"]
+mod day18p1;
+#[path = "../day18p2.rs"]
+mod day18p2;
+#[path = "../day19p1.rs"]
+mod day19p1;
+#[path = "../day19p2.rs"]
+mod day19p2;
+
+pub fn register_all(registry: &mut Registry) {
+    day1p1::register(registry);
+    day1p2::register(registry);
+    day2p1::register(registry);
+    day2p2::register(registry);
+    day2p2_optimized::register(registry);
+    day3p1::register(registry);
+    day3p2::register(registry);
+    day4p1::register(registry);
+    day4p2::register(registry);
+    day5p1::register(registry);
+    day5p2::register(registry);
+    day6p1::register(registry);
+    day6p2::register(registry);
+    day7p1::register(registry);
+    day7p2::register(registry);
+    day8p1::register(registry);
+    day8p2::register(registry);
+    day9p1::register(registry);
+    day9p2::register(registry);
+    day10p1::register(registry);
+    day10p2::register(registry);
+    day11p1::register(registry);
+    day11p2::register(registry);
+    day12p1::register(registry);
+    day12p2::register(registry);
+    day13p1::register(registry);
+    day15p1::register(registry);
+    day15p2::register(registry);
+    day18p1::register(registry);
+    day18p2::register(registry);
+    day19p1::register(registry);
+    day19p2::register(registry);
+}
+
+/// alternate attempts that are only worth timing, `bench` adds these on top of [`register_all`],
+/// they aren't expected to give the right answer so `verify` and `all` never see them
+pub fn register_attempts(registry: &mut Registry) {
+    #[cfg(not(test))]
+    {
+        day4p1a::register(registry);
+        day4p1c::register(registry);
+    }
+}
@@ -3,46 +3,59 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use glam::{IVec2, UVec2};
 use itertools::Itertools;
+use anyhow::bail;
 use smart_default::SmartDefault;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 use advent_of_code::utils::matrix::{MatrixDetails, MatrixIterator};
 use advent_of_code::utils::string::{deformat_string, StringToCharsMatrix};
 use advent_of_code::utils::vec2::{IntoIVec2, IntoUsizeTuple, TryIntoUVec2};
 
-fn main() -> anyhow::Result<()> {
-    let grid: Vec<Vec<_>> = advent_of_code::read_input(10)?
-        .to_chars_matrix()
-        .into_iter()
-        .enumerate()
-        .map(|(y, row)| {
-            row
-                .into_iter()
-                .enumerate()
-                .map(|(x, c)| {
-                    let digit = c
-                        .to_digit(10)
-                        .unwrap_or_else(|| panic!("failed to parse digit from {x},{y}: {c:?}"));
-                    TrailPart {
-                        pos: UVec2::new(x as u32, y as u32),
-                        height: digit,
-                    }
-                })
-                .collect()
-        })
-        .collect();
+struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Vec<Vec<TrailPart>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut grid = vec![];
+        for (y, row) in input.to_chars_matrix().into_iter().enumerate() {
+            let mut grid_row = vec![];
+            for (x, c) in row.into_iter().enumerate() {
+                let Some(digit) = c.to_digit(10) else {
+                    bail!("failed to parse digit from {x},{y}: {c:?}");
+                };
+                grid_row.push(TrailPart {
+                    pos: UVec2::new(x as u32, y as u32),
+                    height: digit,
+                });
+            }
+            grid.push(grid_row);
+        }
+        Ok(grid)
+    }
 
-    let mut iterator = MultiDirectionalPathMatrixIterator::new(&grid);
-    let paths = iterator.process().into_iter().map(|iterator| iterator.path);
+    fn part1(grid: &Self::Input) -> anyhow::Result<Answer> {
+        let iterator = MultiDirectionalPathMatrixIterator::new(grid);
+        let paths = iterator.process().into_iter().map(|iterator| iterator.path);
 
-    let sum = paths.map(|path| {
-        (
-            path.first().unwrap().pos,
-            path.last().unwrap().pos,
-        )
-    }).collect::<HashSet<(UVec2, UVec2)>>().len();
+        let sum = paths.map(|path| {
+            (
+                path.first().unwrap().pos,
+                path.last().unwrap().pos,
+            )
+        }).collect::<HashSet<(UVec2, UVec2)>>().len();
+
+        Ok(sum.into())
+    }
+}
 
-    println!("sum: {}", sum);
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day10>(Part::One);
+}
 
-    Ok(())
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -4,40 +4,45 @@ use std::hash::Hash;
 use glam::{IVec2, UVec2};
 use itertools::Itertools;
 use smart_default::SmartDefault;
-use advent_of_code::utils::matrix::{MatrixDetails, MatrixIterator};
-use advent_of_code::utils::string::{deformat_string, StringToCharsMatrix};
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+use advent_of_code::utils::matrix::{parse_grid, Grid, MatrixDetails, MatrixIterator, ParseGridError};
+use advent_of_code::utils::string::deformat_string;
 use advent_of_code::utils::vec2::{IntoIVec2, IntoUsizeTuple, TryIntoUVec2};
 
-fn main() -> anyhow::Result<()> {
-    let grid: Vec<Vec<_>> = advent_of_code::read_input(10)?
-        .to_chars_matrix()
-        .into_iter()
-        .enumerate()
-        .map(|(y, row)| {
-            row
-                .into_iter()
-                .enumerate()
-                .map(|(x, c)| {
-                    let digit = c
-                        .to_digit(10)
-                        .unwrap_or_else(|| panic!("failed to parse digit from {x},{y}: {c:?}"));
-                    TrailPart {
-                        pos: UVec2::new(x as u32, y as u32),
-                        height: digit,
-                    }
-                })
-                .collect()
-        })
-        .collect();
+struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Vec<Vec<TrailPart>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_trail_map(input)?.to_matrix())
+    }
+
+    fn part2(grid: &Self::Input) -> anyhow::Result<Answer> {
+        let iterator = MultiDirectionalPathMatrixIterator::new(grid);
+        let paths = iterator.process().into_iter().map(|iterator| iterator.path);
 
-    let mut iterator = MultiDirectionalPathMatrixIterator::new(&grid);
-    let paths = iterator.process().into_iter().map(|iterator| iterator.path);
+        let sum = paths.collect::<HashSet<Vec<&TrailPart>>>().len();
 
-    let sum = paths.collect::<HashSet<Vec<&TrailPart>>>().len();
+        Ok(sum.into())
+    }
+}
 
-    println!("sum: {}", sum);
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day10>(Part::Two);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
+}
 
-    Ok(())
+fn parse_trail_map(input: &str) -> Result<Grid<TrailPart>, ParseGridError<&'static str>> {
+    parse_grid(input, |pos, c| {
+        let height = c.to_digit(10).ok_or("expected a digit")?;
+        Ok(TrailPart { pos, height })
+    })
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -318,8 +323,8 @@ impl<'a, T> MultiDirectionalPathMatrixIterator<'a, T> where T: PathItem + Clone
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
-    use advent_of_code::utils::matrix::MatrixIterator;
-    use advent_of_code::utils::string::{deformat_string, StringToCharsMatrix};
+    use advent_of_code::utils::matrix::{Glyph, MatrixIterator, RenderGrid};
+    use advent_of_code::utils::string::deformat_string;
     use super::*;
 
     #[derive(Debug, Copy, Clone)]
@@ -342,26 +347,20 @@ mod tests {
             }).collect()).collect())
         }
         fn print(&self) -> std::fmt::Result {
-            for row in self.0.iter() {
-                for col in row.iter() {
-                    print!("[ ");
-                    if let Some(value) = col {
-                        match value {
-                            DebugCell::Height(value) => print!("{value}"),
-                            DebugCell::End => print!("X"),
-                        }
-                    } else {
-                        print!(" ");
-                    };
-                    print!(" ]");
-                }
-                println!();
-            }
-            println!();
+            println!("{}", self.0.render());
             Ok(())
         }
     }
 
+    impl Glyph for DebugCell {
+        fn glyph(&self) -> char {
+            match self {
+                DebugCell::Height(value) => char::from_digit(*value, 10).unwrap_or('?'),
+                DebugCell::End => 'X',
+            }
+        }
+    }
+
     #[test]
     fn examples() -> anyhow::Result<()> {
         let input = deformat_string("
@@ -374,26 +373,7 @@ mod tests {
             01329801
             10456732
         ");
-        let grid: Vec<Vec<_>> = input
-            .to_chars_matrix()
-            .into_iter()
-            .enumerate()
-            .map(|(y, row)| {
-                row
-                    .into_iter()
-                    .enumerate()
-                    .map(|(x, c)| {
-                        let digit = c
-                            .to_digit(10)
-                            .unwrap_or_else(|| panic!("failed to parse digit from {x},{y}: {c:?}"));
-                        TrailPart {
-                            pos: UVec2::new(x as u32, y as u32),
-                            height: digit,
-                        }
-                    })
-                    .collect()
-            })
-            .collect();
+        let grid = parse_trail_map(&input)?.to_matrix();
 
         let starting_positions = MatrixIterator::new(&grid).map(|(_, item)| item).cloned().filter(TrailPart::is_starting_pos).collect::<Vec<_>>();
         let ending_positions = MatrixIterator::new(&grid).map(|(_, item)| item).cloned().filter(TrailPart::is_ending_pos).collect::<Vec<_>>();
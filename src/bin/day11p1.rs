@@ -1,14 +1,32 @@
 use std::collections::VecDeque;
 use std::fmt::{Display, Formatter};
 use num::{Integer, ToPrimitive};
-use advent_of_code::read_input;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+
+struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = StoneGenerator;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        StoneGenerator::try_from(input)
+    }
+
+    fn part1(gen: &Self::Input) -> anyhow::Result<Answer> {
+        let mut gen = gen.clone();
+        let answer = gen.blinks(25).stones.len();
+        Ok(answer.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day11>(Part::One);
+}
 
 fn main() -> anyhow::Result<()> {
-    let input = read_input(11)?;
-    let mut gen = StoneGenerator::try_from(input)?;
-    let answer = gen.blinks(25).stones.len();
-    println!("Answer: {answer}");
-    Ok(())
+    advent_of_code::solution::run_main(register)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -2,20 +2,33 @@ use std::collections::{HashMap, VecDeque};
 use std::fmt::{Display, Formatter};
 use itertools::Itertools;
 use num::{Integer, ToPrimitive};
-use advent_of_code::read_input;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 
-fn main() -> anyhow::Result<()> {
-    let input = read_input(11)?;
-    let mut gen = StoneGenerator::try_from(input)?;
-    for n in 0..75 {
-        gen.blink();
-        println!("{n}: {}", gen.stone_count());
+struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = StoneGenerator;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        StoneGenerator::try_from(input)
+    }
+
+    fn part2(gen: &Self::Input) -> anyhow::Result<Answer> {
+        let mut gen = gen.clone();
+        let answer = gen.blinks(75).stone_count();
+        Ok(answer.into())
     }
-    let answer = gen.stone_count();
-    println!("Answer: {answer}");
-    Ok(())
 }
 
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day11>(Part::Two);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum EvenOddSplitResult {
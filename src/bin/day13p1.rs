@@ -3,23 +3,41 @@ use glam::UVec2;
 use itertools::iproduct;
 use lazy_static::lazy_static;
 use regex::Regex;
-use advent_of_code::read_input_lines;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 
-fn main() -> anyhow::Result<()> {
-    let lines = read_input_lines(13).map(|line| line.expect("failed to read line")).collect::<Vec<_>>();
-    // this is dirty but meh
-    let mut lines = lines.iter().map(|line| line.as_str());
-    let mut tokens = 0;
-    while let Ok(section) = parse_section(&mut lines) {
-        if let Ok(instance) = section.solve() {
-            println!("solved section with token cost: {}", instance.token_cost());
-            tokens += instance.token_cost();
-        } else {
-            println!("failed to solve section");
+struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input = Vec<Section>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut lines = input.lines();
+        let mut sections = vec![];
+        while let Ok(section) = parse_section(&mut lines) {
+            sections.push(section);
         }
+        Ok(sections)
     }
-    println!("answer: {tokens}");
-    Ok(())
+
+    fn part1(sections: &Self::Input) -> anyhow::Result<Answer> {
+        let mut tokens = 0;
+        for section in sections {
+            if let Ok(instance) = section.solve() {
+                tokens += instance.token_cost();
+            }
+        }
+        Ok(tokens.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day13>(Part::One);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
 
 lazy_static! {
@@ -3,16 +3,34 @@ use std::fmt::{Display, Formatter};
 use anyhow::anyhow;
 use glam::{IVec2, UVec2};
 use itertools::Itertools;
-use advent_of_code::read_input;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 use advent_of_code::utils::matrix::MatrixDetails;
 use advent_of_code::utils::vec2::{IntoIVec2, IntoUsizeTuple, TryIntoUVec2};
 
+struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input = Instance;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(instance: &Self::Input) -> anyhow::Result<Answer> {
+        let mut instance = instance.clone();
+        let gps_sum = instance.process()?;
+        Ok(gps_sum.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day15>(Part::One);
+}
+
 fn main() -> anyhow::Result<()> {
-    let input = read_input(15)?;
-    let mut instance = parse_input(&input)?;
-    let gps_sum = instance.process()?;
-    println!("{}", gps_sum);
-    Ok(())
+    advent_of_code::solution::run_main(register)
 }
 
 fn split_board_and_inputs(input: &str) -> anyhow::Result<(String, String)> {
@@ -103,7 +121,7 @@ enum InstanceStepResult {
     Done,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 struct Instance {
     board: Vec<Vec<Option<Piece>>>,
     robot_pos: UVec2,
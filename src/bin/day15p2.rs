@@ -3,16 +3,43 @@ use std::fmt::{Display, Formatter};
 use anyhow::anyhow;
 use glam::{IVec2, UVec2};
 use itertools::Itertools;
-use advent_of_code::read_input;
-use advent_of_code::utils::matrix::MatrixDetails;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+use advent_of_code::utils::matrix::{parse_grid, Glyph, MatrixDetails, RenderGrid};
+use advent_of_code::utils::recorder::{Recorder, Replay};
 use advent_of_code::utils::vec2::{IntoIVec2, IntoUsizeTuple, TryIntoUVec2};
 
+struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input = Instance;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part2(instance: &Self::Input) -> anyhow::Result<Answer> {
+        let mut instance = instance.clone();
+        let gps_sum = instance.process()?;
+        Ok(gps_sum.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day15>(Part::Two);
+}
+
+/// `--play [fps]` or `--cast <path> [fps]` replays the robot's moves instead of solving
 fn main() -> anyhow::Result<()> {
-    let input = read_input(15)?;
-    let mut instance = parse_input(&input)?;
-    let gps_sum = instance.process()?;
-    println!("{}", gps_sum);
-    Ok(())
+    if let Some(replay) = Replay::from_args(std::env::args().skip(1)) {
+        let mut instance = Day15::parse(&advent_of_code::read_input(Day15::DAY)?)?;
+        let mut recorder = Recorder::new();
+        instance.process_recorded(&mut recorder)?;
+        replay.run(&recorder)?;
+        return Ok(());
+    }
+    advent_of_code::solution::run_main(register)
 }
 
 fn split_board_and_inputs(input: &str) -> anyhow::Result<(String, String)> {
@@ -39,24 +66,27 @@ enum Piece {
     Box(BoxSide),
 }
 
+impl Glyph for Piece {
+    fn glyph(&self) -> char {
+        match self {
+            Piece::Wall => '#',
+            Piece::Robot => '@',
+            Piece::Box(BoxSide::Left) => '[',
+            Piece::Box(BoxSide::Right) => ']',
+        }
+    }
+}
+
+/// every char becomes two cells since the warehouse is twice as wide
 fn parse_board(board: &str) -> anyhow::Result<Vec<Vec<Option<Piece>>>> {
-    board
-        .lines()
-        .map(|row| {
-            row.chars()
-                .map(|c| {
-                    match c {
-                        '#' => Ok((Some(Piece::Wall), Some(Piece::Wall))),
-                        '@' => Ok((Some(Piece::Robot), None)),
-                        'O' => Ok((Some(Piece::Box(BoxSide::Left)), Some(Piece::Box(BoxSide::Right)))),
-                        '.' => Ok((None, None)),
-                        _ => Err(anyhow::anyhow!("unexpected char {}", c)),
-                    }
-                })
-                .collect::<Result<Vec<_>, _>>()
-                .map(|pairs| pairs.into_iter().flat_map(|(a, b)| [a, b]).collect::<Vec<_>>())
-        })
-        .collect::<Result<Vec<_>, _>>()
+    let board = parse_grid(board, |_, c| match c {
+        '#' => Ok((Some(Piece::Wall), Some(Piece::Wall))),
+        '@' => Ok((Some(Piece::Robot), None)),
+        'O' => Ok((Some(Piece::Box(BoxSide::Left)), Some(Piece::Box(BoxSide::Right)))),
+        '.' => Ok((None, None)),
+        _ => Err("unexpected char"),
+    })?;
+    Ok(board.rows().map(|row| row.iter().flat_map(|&(a, b)| [a, b]).collect()).collect())
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -111,7 +141,7 @@ enum InstanceStepResult {
     Done,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 struct Instance {
     board: Vec<Vec<Option<Piece>>>,
     robot_pos: UVec2,
@@ -146,17 +176,27 @@ impl Instance {
     }
     fn step(&mut self) -> InstanceStepResult {
         let Some(direction) = self.inputs.pop_front() else { return InstanceStepResult::Done; };
+        match direction {
+            Direction::Up | Direction::Down => self.push_vertical(direction),
+            Direction::Left | Direction::Right => self.push_horizontal(direction),
+        }
+        if self.inputs.is_empty() {
+            InstanceStepResult::Done
+        } else {
+            InstanceStepResult::Step
+        }
+    }
+    /// boxes in a row only ever push the one box next to them
+    fn push_horizontal(&mut self, direction: Direction) {
         let mut next_pos = self.robot_pos.into_ivec2();
-        let mut newtons_cradle_hanging = vec![];
         let mut newtons_cradle = vec![self.robot_pos];
         while let Ok(pos) = direction.next_pos(next_pos).try_into_uvec2() {
             if !self.matrix_details.is_within_bounds(pos) {
                 break;
             }
             next_pos = pos.into_ivec2();
-            let Ok((x, y)) = next_pos.try_into_uvec2().map(|pos | pos.into_usize_tuple()) else { break; };
-            let cell = &mut self.board[y][x];
-            match cell {
+            let (x, y) = pos.into_usize_tuple();
+            match self.board[y][x] {
                 None => {
                     newtons_cradle.push(pos);
                     break;
@@ -166,33 +206,56 @@ impl Instance {
                     newtons_cradle.clear();
                     break;
                 },
-                Some(Piece::Box(side)) => {
-                    match direction {
-                        Direction::Up | Direction::Down => todo!("we need forking logic"),
-                        _ => {},
-                    }
-                    newtons_cradle.push(pos)
-                },
+                Some(Piece::Box(_)) => newtons_cradle.push(pos),
                 Some(Piece::Robot) => unreachable!(),
             }
         }
-        let Some(robot_pos) = newtons_cradle.get(1) else { return InstanceStepResult::Step };
+        let Some(robot_pos) = newtons_cradle.get(1) else { return };
         self.robot_pos = *robot_pos;
         for (to, from) in newtons_cradle.into_iter().rev().tuple_windows() {
-            match direction {
-                Direction::Up => {}
-                Direction::Down => {}
-                _ => {},
-            }
-            let Some(from) = self.board[from.y as usize][from.x as usize].take() else { unreachable!() };
-            if self.board[to.y as usize][to.x as usize].replace(from).is_some() {
-                unreachable!();
+            self.move_piece(from, to);
+        }
+    }
+    /// a box pushed up or down also pushes whatever is in front of its other half,
+    /// so the pushed pieces fan out a row at a time until every one of them has room or one hits a wall
+    fn push_vertical(&mut self, direction: Direction) {
+        let mut pushed = vec![self.robot_pos];
+        let mut row = vec![self.robot_pos];
+        while !row.is_empty() {
+            let mut next_row = vec![];
+            for pos in row {
+                let Ok(next) = direction.next_pos(pos.into_ivec2()).try_into_uvec2() else { return };
+                if !self.matrix_details.is_within_bounds(next) {
+                    return;
+                }
+                let (x, y) = next.into_usize_tuple();
+                let other_half = match self.board[y][x] {
+                    None => continue,
+                    Some(Piece::Wall) => return,
+                    Some(Piece::Box(BoxSide::Left)) => next + UVec2::X,
+                    Some(Piece::Box(BoxSide::Right)) => next - UVec2::X,
+                    Some(Piece::Robot) => unreachable!(),
+                };
+                for half in [next, other_half] {
+                    if !next_row.contains(&half) {
+                        next_row.push(half);
+                    }
+                }
             }
+            pushed.extend(&next_row);
+            row = next_row;
         }
-        if self.inputs.is_empty() {
-            InstanceStepResult::Done
-        } else {
-            InstanceStepResult::Step
+        // furthest row first so every piece moves into a cell that was already vacated
+        for &from in pushed.iter().rev() {
+            let to = direction.next_pos(from.into_ivec2()).as_uvec2();
+            self.move_piece(from, to);
+        }
+        self.robot_pos = direction.next_pos(self.robot_pos.into_ivec2()).as_uvec2();
+    }
+    fn move_piece(&mut self, from: UVec2, to: UVec2) {
+        let Some(piece) = self.board[from.y as usize][from.x as usize].take() else { unreachable!() };
+        if self.board[to.y as usize][to.x as usize].replace(piece).is_some() {
+            unreachable!();
         }
     }
     fn gps(&mut self) -> usize {
@@ -218,23 +281,24 @@ impl Instance {
             }
         }
     }
+    /// same as `process` but keeps a frame of the warehouse before the first and after every move
+    fn process_recorded(&mut self, recorder: &mut Recorder) -> anyhow::Result<u32> {
+        recorder.record(&*self);
+        loop {
+            match self.step() {
+                InstanceStepResult::Step => recorder.record(&*self),
+                InstanceStepResult::Done => {
+                    recorder.record_final(&*self);
+                    return Ok(self.gps() as u32);
+                }
+            }
+        }
+    }
 }
 
 impl Display for Instance {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for row in self.board.iter() {
-            for col in row {
-                match col {
-                    None => write!(f, ".")?,
-                    Some(Piece::Wall) => write!(f, "#")?,
-                    Some(Piece::Robot) => write!(f, "@")?,
-                    Some(Piece::Box(BoxSide::Left)) => write!(f, "[")?,
-                    Some(Piece::Box(BoxSide::Right)) => write!(f, "]")?,
-                }
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        write!(f, "{}", self.board.render_with(|cell| cell.as_ref().map_or('.', Glyph::glyph)))
     }
 }
 
@@ -273,4 +337,56 @@ mod tests {
         assert_eq!(gps_sum, 9021);
         Ok(())
     }
+
+    #[test]
+    fn process_recorded_test() -> anyhow::Result<()> {
+        let input = deformat_string("
+            #######
+            #.@O..#
+            #######
+
+            >>><<
+        ");
+        let mut instance = parse_input(&input)?;
+        let mut recorder = Recorder::new();
+        assert_eq!(instance.process_recorded(&mut recorder)?, 108);
+        let rows = recorder.frames().iter().map(|frame| frame.text.lines().nth(1).unwrap()).collect::<Vec<_>>();
+        assert_eq!(rows, vec![
+            "##..@.[]....##",
+            "##...@[]....##",
+            "##....@[]...##",
+            "##.....@[]..##",
+            "##....@.[]..##",
+            "##...@..[]..##",
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn vertical_push_test() -> anyhow::Result<()> {
+        let input = deformat_string("
+            #######
+            #...#.#
+            #.....#
+            #..OO@#
+            #..O..#
+            #.....#
+            #######
+
+            <vv<<^^<<^^
+        ");
+        let mut instance = parse_input(&input)?;
+        assert_eq!(instance.process()?, 618);
+        let expected = deformat_string("
+            ##############
+            ##...[].##..##
+            ##...@.[]...##
+            ##....[]....##
+            ##..........##
+            ##..........##
+            ##############
+        ");
+        assert_eq!(instance.to_string(), expected + "\n");
+        Ok(())
+    }
 }
@@ -3,20 +3,42 @@ use pathfinding::prelude::astar;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use itertools::Itertools;
-use advent_of_code::read_input;
+use anyhow::bail;
+use tracing::{debug, trace};
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+use advent_of_code::utils::matrix::{Glyph, Grid, MatrixDetails, RenderGrid, SparseGrid};
+use glam::{IVec2, UVec2};
+
+/// width and height of the memory space
+const MEMORY_SIZE: u32 = 71;
+
+struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input = Memory;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let input = input.lines().take(1024).join("\n");
+        Memory::parse(&input, MEMORY_SIZE)
+    }
+
+    fn part1(memory: &Self::Input) -> anyhow::Result<Answer> {
+        let Some(path) = memory.find_path()? else {
+            bail!("no path found");
+        };
+        let steps = path.len() - 1;
+        Ok(steps.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day18>(Part::One);
+}
 
 fn main() -> anyhow::Result<()> {
-    let input = read_input(18)?;
-    let input = input.lines().take(1024).join("\n");
-    let memory = Memory::try_from(input.as_str())?;
-    let path = memory.find_path()?;
-    println!();
-    println!();
-    println!("{memory}");
-    println!("{path:?}");
-    let steps = path.unwrap().len() - 1;
-    println!("Steps: {}", steps);
-    Ok(())
+    advent_of_code::solution::run_main(register)
 }
 
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -26,27 +48,44 @@ enum MemoryCell {
     Corrupt,
 }
 
-impl Display for MemoryCell {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl Glyph for MemoryCell {
+    fn glyph(&self) -> char {
         match self {
-            MemoryCell::Safe => write!(f, "."),
-            MemoryCell::Corrupt => write!(f, "#"),
+            MemoryCell::Safe => '.',
+            MemoryCell::Corrupt => '#',
         }
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(transparent)]
-struct Memory(Vec<Vec<MemoryCell>>);
+struct Memory(SparseGrid<MemoryCell>);
 
 impl Memory {
+    /// a `size` x `size` memory space with nothing corrupted yet
+    fn new(size: u32) -> Self {
+        let bounds = MatrixDetails::from_size(IVec2::ZERO, UVec2::splat(size));
+        Self(SparseGrid::with_fixed_bounds(MemoryCell::Safe, bounds))
+    }
+    /// corrupts every byte listed in `input`, bytes outside of the memory space are an error
+    fn parse(input: &str, size: u32) -> anyhow::Result<Self> {
+        let mut memory = Self::new(size);
+        process_lines(&mut memory, input)?;
+        Ok(memory)
+    }
+    /// dense copy of the whole memory space
+    fn to_grid(&self) -> Grid<MemoryCell> {
+        self.0.to_grid()
+    }
     fn find_path(&self) -> anyhow::Result<Option<Vec<(usize, usize)>>> {
+        let grid = self.to_grid();
         let goal = (
-            self.0[0].len().saturating_sub(1) as i32,
-            self.0.len().saturating_sub(1) as i32,
+            grid.width().saturating_sub(1) as i32,
+            grid.height().saturating_sub(1) as i32,
         );
-        println!("goal: {goal:?}");
-        if self.0[0][0] != MemoryCell::Safe || self.0[goal.1 as usize][goal.0 as usize] != MemoryCell::Safe {
+        debug!(?goal, "finding path");
+        let is_safe = |(x, y): (i32, i32)| grid.get(IVec2::new(x, y)) == Some(&MemoryCell::Safe);
+        if !is_safe((0, 0)) || !is_safe(goal) {
             return Ok(None);
         }
         let result = astar(
@@ -58,14 +97,13 @@ impl Memory {
                     (x, y + 1),
                     (x, y - 1),
                 ];
-                println!("Neighbors for ({x}, {y}): {neighbors:?}");
+                trace!("Neighbors for ({x}, {y}): {neighbors:?}");
                 neighbors
                     .into_iter()
                     .filter(|&(nx, ny)| {
-                        let in_bounds = nx >= 0 && ny >= 0 && nx <= goal.0 && ny <= goal.1;
-                        let is_safe = in_bounds && self.0[ny as usize][nx as usize] == MemoryCell::Safe;
+                        let is_safe = is_safe((nx, ny));
                         if !is_safe {
-                            println!("Filtered out neighbor ({nx}, {ny}): in_bounds={in_bounds}, is_safe={is_safe}");
+                            trace!("Filtered out neighbor ({nx}, {ny}): is_safe={is_safe}");
                         }
                         is_safe
                     })
@@ -73,7 +111,7 @@ impl Memory {
                     .collect::<Vec<_>>()
             },
             |&(x, y)| {
-                println!(
+                trace!(
                     "Heuristic for ({x}, {y}) -> ({gx}, {gy}): {}",
 
                     (goal.0 - x).abs() + (goal.1 - y).abs(),
@@ -84,7 +122,7 @@ impl Memory {
             },
             |&p| p == goal,
         );
-        println!("Result: {:?}", result);
+        debug!(?result, "path search finished");
         let result = result.map(|(path, _cost)| {
             path.into_iter()
                 .map(|pos| (pos.0 as usize, pos.1 as usize))
@@ -96,47 +134,18 @@ impl Memory {
 
 impl Display for Memory {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for row in &self.0 {
-            for cell in row {
-                write!(f, "{}", cell)?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
-    }
-}
-
-impl TryFrom<&str> for Memory {
-    type Error = anyhow::Error;
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        process_str(value)
+        write!(f, "{}", self.to_grid().render())
     }
 }
 
 fn process_line(memory: &mut Memory, line: &str) -> anyhow::Result<()> {
-    let mut parts = line.trim().split(',').map(str::parse::<usize>);
+    let mut parts = line.trim().split(',').map(str::parse::<i32>);
     let x = parts.next().expect("unexpected input")?;
     let y = parts.next().expect("unexpected input")?;
     assert!(parts.next().is_none());
 
-    let width = (if memory.0.is_empty() {
-        0
-    } else {
-        memory.0[0].len()
-    })
-    .max(x + 1);
-
-    if memory.0.len() <= y {
-        memory.0.resize(y + 1, vec![MemoryCell::Safe; width]);
-    }
-
-    if memory.0[0].len() <= width {
-        for row in &mut memory.0 {
-            row.resize(width, MemoryCell::Safe);
-        }
-    }
-    println!("{:?} {:?}", x, y);
-    memory.0[y][x] = MemoryCell::Corrupt;
+    trace!(x, y, "corrupted");
+    memory.0.set(IVec2::new(x, y), MemoryCell::Corrupt)?;
     Ok(())
 }
 
@@ -147,12 +156,6 @@ fn process_lines(memory: &mut Memory, lines: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn process_str(input: &str) -> anyhow::Result<Memory> {
-    let mut memory = Memory::default();
-    process_lines(&mut memory, input)?;
-    Ok(memory)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,7 +193,7 @@ mod tests {
         ",
         );
         let input = input.lines().take(12).join("\n");
-        let memory = Memory::try_from(input.as_str())?;
+        let memory = Memory::parse(&input, 7)?;
         println!("{memory}");
 
         let mut expected = deformat_string(
@@ -207,9 +210,15 @@ mod tests {
         expected.push('\n');
 
         assert_eq!(format!("{memory}"), expected,);
+        assert!(Memory::parse("7,0", 7).is_err());
         let path = memory.find_path()?;
         assert!(path.is_some());
-        assert_eq!(path.map(|path| path.len() - 1), Some(22));
+        let path = path.unwrap_or_default();
+        assert_eq!(path.len() - 1, 22);
+
+        let grid = memory.to_grid();
+        let rendered = grid.render().path_with(path.iter().map(|&(x, y)| UVec2::new(x as u32, y as u32)), 'O');
+        assert_eq!(rendered.to_string().matches('O').count(), 23);
         Ok(())
     }
 }
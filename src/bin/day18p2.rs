@@ -3,29 +3,55 @@ use pathfinding::prelude::astar;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use itertools::Itertools;
-use advent_of_code::read_input;
+use anyhow::bail;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+use advent_of_code::utils::matrix::{Glyph, Grid, MatrixDetails, RenderGrid, SparseGrid};
+use glam::{IVec2, UVec2};
 
-// this is dirty but instead of rewriting Memory to not have to reparse everything each iteration
-// we just ran it like this to get the answer
-fn main() -> anyhow::Result<()> {
-    let input = read_input(18)?;
-    let mut ix = 0;
-    let mut blocked_by = None;
-    loop {
-        ix += 1;
-        println!("ix: {}", ix);
-        let lines = input.lines().take(ix);
-        let input = lines.clone().join("\n");
-        let mut memory = Memory::try_from(input.as_str())?;
-        memory.set_size(71);
-        let path = memory.find_path()?;
-        if path.is_none() {
-            blocked_by = Some(lines.last().unwrap());
-            break;
+/// width and height of the memory space
+const MEMORY_SIZE: u32 = 71;
+
+struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input = String;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    // this is dirty but instead of rewriting Memory to not have to reparse everything each iteration
+    // we just ran it like this to get the answer
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        let total = input.lines().count();
+        let mut ix = 0;
+        loop {
+            ix += 1;
+            let lines = input.lines().take(ix);
+            let input = lines.clone().join("\n");
+            let memory = Memory::parse(&input, MEMORY_SIZE)?;
+            let path = memory.find_path()?;
+            if path.is_none() {
+                let Some(blocked_by) = lines.last() else {
+                    bail!("no corrupted bytes");
+                };
+                return Ok(blocked_by.into());
+            }
+            if ix >= total {
+                bail!("path was never blocked");
+            }
         }
     }
-    println!("Blocked by: {:?}", blocked_by);
-    Ok(())
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day18>(Part::Two);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
 
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -35,33 +61,44 @@ enum MemoryCell {
     Corrupt,
 }
 
-impl Display for MemoryCell {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl Glyph for MemoryCell {
+    fn glyph(&self) -> char {
         match self {
-            MemoryCell::Safe => write!(f, "."),
-            MemoryCell::Corrupt => write!(f, "#"),
+            MemoryCell::Safe => '.',
+            MemoryCell::Corrupt => '#',
         }
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(transparent)]
-struct Memory(Vec<Vec<MemoryCell>>);
+struct Memory(SparseGrid<MemoryCell>);
 
 impl Memory {
-    fn set_size(&mut self, size: usize) {
-        for row in &mut self.0 {
-            row.resize(size, MemoryCell::Safe);
-        }
-        self.0.resize(size, vec![MemoryCell::Safe; size]);
+    /// a `size` x `size` memory space with nothing corrupted yet
+    fn new(size: u32) -> Self {
+        let bounds = MatrixDetails::from_size(IVec2::ZERO, UVec2::splat(size));
+        Self(SparseGrid::with_fixed_bounds(MemoryCell::Safe, bounds))
+    }
+    /// corrupts every byte listed in `input`, bytes outside of the memory space are an error
+    fn parse(input: &str, size: u32) -> anyhow::Result<Self> {
+        let mut memory = Self::new(size);
+        process_lines(&mut memory, input)?;
+        Ok(memory)
+    }
+    /// dense copy of the whole memory space
+    fn to_grid(&self) -> Grid<MemoryCell> {
+        self.0.to_grid()
     }
     fn find_path(&self) -> anyhow::Result<Option<Vec<(usize, usize)>>> {
+        let grid = self.to_grid();
         let goal = (
-            self.0[0].len().saturating_sub(1) as i32,
-            self.0.len().saturating_sub(1) as i32,
+            grid.width().saturating_sub(1) as i32,
+            grid.height().saturating_sub(1) as i32,
         );
         // println!("goal: {goal:?}");
-        if self.0[0][0] != MemoryCell::Safe || self.0[goal.1 as usize][goal.0 as usize] != MemoryCell::Safe {
+        let is_safe = |(x, y): (i32, i32)| grid.get(IVec2::new(x, y)) == Some(&MemoryCell::Safe);
+        if !is_safe((0, 0)) || !is_safe(goal) {
             return Ok(None);
         }
         let result = astar(
@@ -77,10 +114,9 @@ impl Memory {
                 neighbors
                     .into_iter()
                     .filter(|&(nx, ny)| {
-                        let in_bounds = nx >= 0 && ny >= 0 && nx <= goal.0 && ny <= goal.1;
-                        let is_safe = in_bounds && self.0[ny as usize][nx as usize] == MemoryCell::Safe;
+                        let is_safe = is_safe((nx, ny));
                         if !is_safe {
-                            // println!("Filtered out neighbor ({nx}, {ny}): in_bounds={in_bounds}, is_safe={is_safe}");
+                            // println!("Filtered out neighbor ({nx}, {ny}): is_safe={is_safe}");
                         }
                         is_safe
                     })
@@ -111,47 +147,18 @@ impl Memory {
 
 impl Display for Memory {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for row in &self.0 {
-            for cell in row {
-                write!(f, "{}", cell)?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
-    }
-}
-
-impl TryFrom<&str> for Memory {
-    type Error = anyhow::Error;
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        process_str(value)
+        write!(f, "{}", self.to_grid().render())
     }
 }
 
 fn process_line(memory: &mut Memory, line: &str) -> anyhow::Result<()> {
-    let mut parts = line.trim().split(',').map(str::parse::<usize>);
+    let mut parts = line.trim().split(',').map(str::parse::<i32>);
     let x = parts.next().expect("unexpected input")?;
     let y = parts.next().expect("unexpected input")?;
     assert!(parts.next().is_none());
 
-    let width = (if memory.0.is_empty() {
-        0
-    } else {
-        memory.0[0].len()
-    })
-    .max(x + 1);
-
-    if memory.0.len() <= y {
-        memory.0.resize(y + 1, vec![MemoryCell::Safe; width]);
-    }
-
-    if memory.0[0].len() <= width {
-        for row in &mut memory.0 {
-            row.resize(width, MemoryCell::Safe);
-        }
-    }
     // println!("{:?} {:?}", x, y);
-    memory.0[y][x] = MemoryCell::Corrupt;
+    memory.0.set(IVec2::new(x, y), MemoryCell::Corrupt)?;
     Ok(())
 }
 
@@ -162,12 +169,6 @@ fn process_lines(memory: &mut Memory, lines: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn process_str(input: &str) -> anyhow::Result<Memory> {
-    let mut memory = Memory::default();
-    process_lines(&mut memory, input)?;
-    Ok(memory)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,8 +211,7 @@ mod tests {
             ix += 1;
             let lines = input.lines().take(ix);
             let input = lines.clone().join("\n");
-            let mut memory = Memory::try_from(input.as_str())?;
-            memory.set_size(7);
+            let memory = Memory::parse(&input, 7)?;
             let path = memory.find_path()?;
             if path.is_none() {
                 blocked_by = Some(lines.last().unwrap());
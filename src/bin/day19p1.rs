@@ -1,17 +1,30 @@
 use std::collections::HashSet;
-use advent_of_code::read_input;
+use tracing::trace;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+
+struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input = (PatternSet, Vec<RequestPattern>);
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((pattern_set, requests): &Self::Input) -> anyhow::Result<Answer> {
+        let valid_count = requests.iter().filter(|request| pattern_set.can_print(request)).count();
+        Ok(valid_count.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day19>(Part::One);
+}
 
 fn main() -> anyhow::Result<()> {
-    let input = read_input(19)?;
-    let (pattern_set, requests) = parse_input(&input)?;
-    println!("pattern_set: {pattern_set:?}");
-    println!("request count: {}", requests.len());
-    let valid_count = requests.iter().filter(|request| {
-        let can_print = pattern_set.can_print(request);
-        println!("{}: {}", request.pattern, can_print);
-        can_print
-    }).count();
-    Ok(())
+    advent_of_code::solution::run_main(register)
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -56,7 +69,7 @@ impl PatternSet {
                     }
                 } else if pattern_char == Some(char) {
                     if is_last_char && left_pattern.len() - 1 == ix {
-                        println!("complete: {left_pattern}");
+                        trace!("complete: {left_pattern}");
                         // if is_last_char and left len - 1 matches ix then we are done
                         pattern_sets.push(left_pattern);
                     } else if !is_last_char {
@@ -1,27 +1,33 @@
-use advent_of_code::read_input;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 use std::collections::HashSet;
+use tracing::{debug, trace};
+
+struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input = (PatternSet, Vec<RequestPattern>);
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part2((pattern_set, requests): &Self::Input) -> anyhow::Result<Answer> {
+        let sum = requests
+            .iter()
+            .map(|request| pattern_set.matches(request).len())
+            .sum::<usize>();
+        Ok(sum.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day19>(Part::Two);
+}
 
 fn main() -> anyhow::Result<()> {
-    let input = read_input(19)?;
-    let (pattern_set, requests) = parse_input(&input)?;
-    println!("pattern_set: {pattern_set:?}");
-    println!("request count: {}", requests.len());
-    println!("finding matches");
-    let sum = requests
-        .into_iter()
-        .map(|request| {
-            println!("{}:", request.pattern);
-            let matches = pattern_set.matches(&request);
-            let len = matches.len();
-            println!("# matches: {len}");
-            for match_item in matches {
-                println!("{match_item:?}");
-            }
-            len
-        })
-        .sum::<usize>();
-    println!("sum: {sum}");
-    Ok(())
+    advent_of_code::solution::run_main(register)
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -46,7 +52,7 @@ impl PatternSet {
             .collect::<HashSet<_>>();
         let mut chars = request_pattern.pattern.chars().enumerate().peekable();
         while let Some((ix, char)) = chars.next() {
-            println!("searching {char} @ {ix:>3}/{total_len:<3} {}", branches.len());
+            debug!("searching {char} @ {ix:>3}/{total_len:<3} {}", branches.len());
             let is_last_char = chars.peek().is_none();
             let mut new_branches = HashSet::with_capacity(branches.len());;
             for (splits, left_pattern) in branches.into_iter() {
@@ -81,7 +87,7 @@ impl PatternSet {
                     }
                 } else if pattern_char == Some(char) {
                     if is_last_char && left_pattern.len() - 1 == ix {
-                        println!("complete: {left_pattern}");
+                        trace!("complete: {left_pattern}");
                         // if is_last_char and left len - 1 matches ix then we are done
                         let item = (splits, left_pattern);
                         if !pattern_sets.contains(&item) {
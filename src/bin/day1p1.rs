@@ -1,4 +1,6 @@
-use std::error::Error;
+use anyhow::bail;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 
 pub trait SortCopied {
     type Item;
@@ -25,21 +27,39 @@ fn calc_distance(a: &[u32], b: &[u32]) -> u32 {
     distance
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let lines = advent_of_code::read_input_lines(1);
-    let mut a = Vec::new();
-    let mut b = Vec::new();
-    for input_line in lines {
-        let input_line = input_line?;
-        let input_line = input_line
-            .trim()
-            .split_whitespace()
-            .map(|x| x.parse::<u32>().expect("Invalid input"))
-            .collect::<Vec<_>>();
-        a.push(input_line[0]);
-        b.push(input_line[1]);
+struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+        for (ix, input_line) in input.lines().enumerate() {
+            let input_line = input_line
+                .trim()
+                .split_whitespace()
+                .map(|x| x.parse::<u32>())
+                .collect::<Result<Vec<_>, _>>()?;
+            let [left, right] = input_line[..] else {
+                bail!("line {}: expected 2 numbers, found {}", ix + 1, input_line.len());
+            };
+            a.push(left);
+            b.push(right);
+        }
+        Ok((a, b))
+    }
+
+    fn part1((a, b): &Self::Input) -> anyhow::Result<Answer> {
+        Ok(calc_distance(a, b).into())
     }
-    let answer = calc_distance(&a, &b);
-    println!("Answer: {answer}");
-    Ok(())
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day1>(Part::One);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
@@ -1,6 +1,7 @@
-use std::error::Error;
 use std::num::TryFromIntError;
 use thiserror::Error;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 enum Direction {
@@ -86,28 +87,44 @@ fn validate_report(report: &Vec<u32>) -> Result<(), IsNotSafeError> {
     Ok(())
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let lines = advent_of_code::read_input_lines(2);
-    let mut reports = Vec::new();
-    for input_line in lines {
-        let input_line = input_line?;
-        let report = input_line
-            .trim()
-            .split_whitespace()
-            .map(|x| x.parse::<u32>().expect("Invalid input"))
-            .collect::<Vec<_>>();
-        reports.push(report);
+struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut reports = Vec::new();
+        for input_line in input.lines() {
+            let report = input_line
+                .trim()
+                .split_whitespace()
+                .map(|x| x.parse::<u32>())
+                .collect::<Result<Vec<_>, _>>()?;
+            reports.push(report);
+        }
+        Ok(reports)
     }
-    fn is_report_safe(report: &Vec<u32>) -> bool {
-        if let Err(err) = validate_report(&report) {
-            if let IsNotSafeError::U32OperationError(err) = err {
-                panic!("U32 Operation Error: {err:?}");
+
+    fn part1(reports: &Self::Input) -> anyhow::Result<Answer> {
+        fn is_report_safe(report: &Vec<u32>) -> bool {
+            if let Err(err) = validate_report(&report) {
+                if let IsNotSafeError::U32OperationError(err) = err {
+                    panic!("U32 Operation Error: {err:?}");
+                }
+                return false
             }
-            return false
+            true
         }
-        true
+        let answer = reports.iter().filter(|report| is_report_safe(report)).count();
+        Ok(answer.into())
     }
-    let answer = reports.into_iter().filter(is_report_safe).count();
-    println!("Answer: {answer}");
-    Ok(())
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day2>(Part::One);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
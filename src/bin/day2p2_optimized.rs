@@ -1,6 +1,7 @@
-use std::error::Error;
 use std::num::TryFromIntError;
 use thiserror::Error;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 
 #[derive(Debug, Clone)]
 struct Report {
@@ -42,14 +43,14 @@ impl Report {
             return Some((false, self.clone()));
         }
 
-        let invalid_distances = self.collect_indices(IsNotSafeError::InvalidDistance);
+        let invalid_distances = self.collect_indices(|error| matches!(error, IsNotSafeError::InvalidDistance { .. }));
         if is_single_or_adjacent(&invalid_distances) {
             if let Some(fixed) = self.try_fix_invalid_indices(&invalid_distances) {
                 return Some(fixed);
             }
         }
 
-        let mixed_directions = self.collect_indices(IsNotSafeError::MixedDirection);
+        let mixed_directions = self.collect_indices(|error| matches!(error, IsNotSafeError::MixedDirection { .. }));
         if is_single_or_adjacent(&mixed_directions) {
             if let Some(fixed) = self.try_fix_invalid_indices(&mixed_directions) {
                 return Some(fixed);
@@ -247,26 +248,41 @@ fn is_single_or_adjacent(items: &[usize]) -> bool {
     }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let lines = advent_of_code::read_input_lines(2);
-    let mut reports = Vec::new();
-    for input_line in lines {
-        let input_line = input_line?;
-        let data = input_line
-            .trim()
-            .split_whitespace()
-            .map(|x| x.parse::<u32>().expect("Invalid input"))
-            .collect::<Vec<_>>();
-        reports.push(Report::from_data(data));
+struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    type Input = Vec<Report>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut reports = Vec::new();
+        for input_line in input.lines() {
+            let data = input_line
+                .trim()
+                .split_whitespace()
+                .map(|x| x.parse::<u32>())
+                .collect::<Result<Vec<_>, _>>()?;
+            reports.push(Report::from_data(data));
+        }
+        Ok(reports)
     }
 
-    fn is_report_safe(report: &Report) -> bool {
-        report.fix_cloned().map(|(_, report)| report).is_some()
+    fn part2(reports: &Self::Input) -> anyhow::Result<Answer> {
+        fn is_report_safe(report: &&Report) -> bool {
+            report.fix_cloned().map(|(_, report)| report).is_some()
+        }
+
+        let answer = reports.iter().filter(is_report_safe).count();
+        Ok(answer.into())
     }
+}
 
-    let answer = reports.into_iter().filter(is_report_safe).count();
-    println!("Answer: {answer}");
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.register_variant::<Day2>(Part::Two, "optimized");
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
 
 #[cfg(test)]
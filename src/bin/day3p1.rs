@@ -1,27 +1,38 @@
 use regex::Regex;
-use std::error::Error;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let lines = advent_of_code::read_input_lines(3);
-    let mul_regex = Regex::new(r"mul\((\d+),(\d+)\)").expect("invalid regex");
-    let mut result = 0;
-    for input_line in lines {
-        let input_line = input_line?;
-        let input_line = input_line.trim();
-        let matches: Vec<(i32, i32)> = mul_regex
-            .captures_iter(input_line)
-            .map(|cap| {
-                (
-                    cap[1].parse::<i32>().expect("failed to parse int"),
-                    cap[2].parse::<i32>().expect("failed to parse int"),
-                )
-            })
-            .collect();
+struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    type Input = Vec<(i32, i32)>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mul_regex = Regex::new(r"mul\((\d+),(\d+)\)").expect("invalid regex");
+        let mut matches = Vec::new();
+        for input_line in input.lines() {
+            let input_line = input_line.trim();
+            for cap in mul_regex.captures_iter(input_line) {
+                matches.push((cap[1].parse::<i32>()?, cap[2].parse::<i32>()?));
+            }
+        }
+        Ok(matches)
+    }
+
+    fn part1(matches: &Self::Input) -> anyhow::Result<Answer> {
+        let mut result = 0;
         for (a, b) in matches {
             result += a * b;
         }
+        Ok(result.into())
     }
-    let answer = result;
-    println!("Answer: {answer}");
-    Ok(())
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day3>(Part::One);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
@@ -2,7 +2,10 @@ extern crate core;
 
 use regex::{Captures, Match, Regex};
 use std::error::Error;
-use std::io::{BufReader, Lines, Read};
+use std::io::{BufRead, BufReader, Lines, Read};
+use anyhow::anyhow;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 
 struct MatchDetails<T> {
     start: usize,
@@ -95,12 +98,29 @@ fn parse_lines<T: Read>(lines: Lines<BufReader<T>>) -> Result<i32, Box<dyn Error
     Ok(result)
 }
 
+struct Day3;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let lines = advent_of_code::read_input_lines(3);
-    let answer = parse_lines(lines)?;
-    println!("Answer: {answer}");
-    Ok(())
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    type Input = String;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        let lines = BufReader::new(input.as_bytes()).lines();
+        let answer = parse_lines(lines).map_err(|err| anyhow!("{err}"))?;
+        Ok(answer.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day3>(Part::Two);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
 
 #[cfg(test)]
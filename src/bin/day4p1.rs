@@ -1,5 +1,7 @@
 use lazy_static::lazy_static;
-use advent_of_code::utils::matrix::{rotate_matrix_180_degrees, rotate_matrix_270_degrees, rotate_matrix_90_degrees, skew_matrix_by};
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+use advent_of_code::utils::matrix::{MatrixView, PatternSet, Transform};
 
 lazy_static! {
     static ref PATTERN: Vec<Vec<Option<char>>> = {
@@ -10,16 +12,32 @@ lazy_static! {
 }
 
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let lines = advent_of_code::read_input_lines(4);
-    let mut data = vec![];
-    for input_line in lines {
-        let input_line = input_line?;
-        let input_line = input_line.trim();
-        data.push(input_line.chars().collect::<Vec<_>>());
+struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.lines().map(|line| line.trim().chars().collect()).collect())
+    }
+
+    fn part1(grid: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(patterns().find(grid).len().into())
     }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day4>(Part::One);
+}
 
-    let base_patterns = vec![
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
+}
+
+/// every rotation of XMAS and its diagonal
+fn patterns() -> PatternSet<char> {
+    let patterns = [
         PATTERN.clone(), // XMAS
         /*
             X
@@ -27,69 +45,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
               A
                S
          */
-        skew_matrix_by(&PATTERN, 1),
+        PATTERN.view().skew(1).to_matrix(),
     ];
-    let mut patterns = vec![];
-    patterns.extend(base_patterns.clone());
-    patterns.extend(base_patterns.iter().map(rotate_matrix_90_degrees).collect::<Vec<_>>());
-    patterns.extend(base_patterns.iter().map(rotate_matrix_180_degrees).collect::<Vec<_>>());
-    patterns.extend(base_patterns.iter().map(rotate_matrix_270_degrees).collect::<Vec<_>>());
-
-    let rows = data.len();
-    let cols = data[0].len();
-
-    let mut count = 0;
-    for pattern in patterns {
-        let pat_rows = pattern.len();
-        let pat_cols = pattern[0].len();
-        for row in 0..rows {
-            for col in 0..cols {
-                if matches_pattern(&data, &pattern, row, col, rows, cols, pat_rows, pat_cols) {
-                    count += 1;
-                }
-            }
-        }
-    }
-    let answer = count;
-    println!("Answer: {answer}");
-    Ok(())
-}
-
-fn matches_pattern(
-    grid: &Vec<Vec<char>>,
-    pattern: &Vec<Vec<Option<char>>>,
-    start_row: usize,
-    start_col: usize,
-    rows: usize,
-    cols: usize,
-    pat_rows: usize,
-    pat_cols: usize,
-) -> bool {
-    if start_row + pat_rows > rows || start_col + pat_cols > cols {
-        return false; // Out of bounds for the pattern
-    }
-
-    for pr in 0..pat_rows {
-        for pc in 0..pat_cols {
-            let pat_char = pattern[pr][pc];
-            let grid_char = grid[start_row + pr][start_col + pc];
-
-            if let Some(pat_char) = pat_char {
-                if pat_char == grid_char {
-                    continue;
-                }
-                return false;
-            }
-        }
-    }
-
-    true
+    PatternSet::new(&patterns, Some(&Transform::ROTATIONS))
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
-    use advent_of_code::utils::matrix::{matrix_diff, skew_matrix_by};
+    use glam::UVec2;
+    use advent_of_code::utils::matrix::{assert_matrix_eq, find_pattern};
     use advent_of_code::utils::string::{deformat_string, CharsMatrixToString, StringToCharsMatrix};
     use super::*;
 
@@ -105,8 +70,8 @@ mod tests {
             vec![None, None, Some('3')],
             vec![None, Some('B'), None],
         ];
-        assert!(matches_pattern(&input, &pattern, 0, 0, 3, 3, 3, 3));
-        assert!(!matches_pattern(&input, &pattern, 1, 1, 3, 3, 3, 3));
+        let origins = find_pattern(&input, &pattern, None).into_iter().map(|found| found.origin).collect::<Vec<_>>();
+        assert_eq!(origins, vec![UVec2::new(0, 0)]);
     }
 
     #[test]
@@ -138,30 +103,7 @@ mod tests {
         let grid = input.to_chars_matrix();
         let expected = expected.to_chars_matrix();
 
-        let base_patterns = vec![
-            PATTERN.clone(), // XMAS
-            /*
-                X
-                 M
-                  A
-                   S
-             */
-            skew_matrix_by(&PATTERN, 1),
-        ];
-        let mut patterns = vec![];
-        patterns.extend(base_patterns.clone());
-        patterns.extend(base_patterns.iter().map(rotate_matrix_90_degrees).collect::<Vec<_>>());
-        patterns.extend(base_patterns.iter().map(rotate_matrix_180_degrees).collect::<Vec<_>>());
-        patterns.extend(base_patterns.iter().map(rotate_matrix_270_degrees).collect::<Vec<_>>());
-
-        println!("Patterns:\n");
-        patterns.iter().for_each(|pattern| {
-            println!("{}", pattern.to_string());
-        });
-        println!();
-
-        let rows = grid.len();
-        let cols = grid[0].len();
+        let patterns = patterns();
 
         let mut individual_match_visualization_data = grid.clone()
             .into_iter()
@@ -184,37 +126,22 @@ mod tests {
         let mut matches = HashSet::new();
 
         let mut count = 0;
-        for pattern in patterns {
-            let pat_rows = pattern.len();
-            let pat_cols = pattern[0].len();
-            for row in 0..rows {
-                for col in 0..cols {
-                    if matches_pattern(&grid, &pattern, row, col, rows, cols, pat_rows, pat_cols) {
-                        count += 1;
-                        let mut debug_individual_match_visualization = individual_match_visualization_data.clone();
-                        for (y, line) in pattern.iter().enumerate() {
-                            for (x, char) in line.iter().enumerate() {
-                                if char.is_none() {
-                                    continue;
-                                }
-                                let y = row + y;
-                                let x = col + x;
-                                match_visualization_data[y][x] = grid[y][x];
-                                debug_individual_match_visualization[y][x] = grid[y][x];
-                            }
-                        }
-                        if !matches.insert(debug_individual_match_visualization.to_string()) {
-                            panic!("duplicate match");
-                        }
-                        println!("Pattern\n{}", pattern.to_string());
-                        println!("Match\n{}", debug_individual_match_visualization.to_string());
-                    }
-                }
+        for found in patterns.find(&grid) {
+            count += 1;
+            let mut debug_individual_match_visualization = individual_match_visualization_data.clone();
+            for pos in patterns.matched_cells(&found) {
+                let (x, y) = (pos.x as usize, pos.y as usize);
+                match_visualization_data[y][x] = grid[y][x];
+                debug_individual_match_visualization[y][x] = grid[y][x];
+            }
+            if !matches.insert(debug_individual_match_visualization.to_string()) {
+                panic!("duplicate match");
             }
+            println!("Pattern {} {:?}", found.pattern, found.transform);
+            println!("Match\n{}", debug_individual_match_visualization.to_string());
         }
         println!("{}", match_visualization_data.to_string());
-        println!("diff:\n{}", matrix_diff(&expected, &match_visualization_data, |_, b| *b).to_string());
-        assert_eq!(match_visualization_data, expected);
+        assert_matrix_eq(&match_visualization_data, &expected);
         assert_eq!(count, 18);
         Ok(())
     }
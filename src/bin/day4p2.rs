@@ -1,5 +1,7 @@
 use lazy_static::lazy_static;
-use advent_of_code::utils::matrix::{rotate_matrix_180_degrees, rotate_matrix_270_degrees, rotate_matrix_90_degrees};
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+use advent_of_code::utils::matrix::{find_pattern, Transform};
 
 lazy_static! {
     static ref PATTERN: Vec<Vec<Option<char>>> = {
@@ -11,75 +13,32 @@ lazy_static! {
     };
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let lines = advent_of_code::read_input_lines(4);
-    let mut data = vec![];
-    for input_line in lines {
-        let input_line = input_line?;
-        let input_line = input_line.trim();
-        data.push(input_line.chars().collect::<Vec<_>>());
-    }
-
-    let base_patterns = vec![PATTERN.clone()];
-    let mut patterns = vec![];
-    patterns.extend(base_patterns.clone());
-    patterns.extend(base_patterns.iter().map(rotate_matrix_90_degrees).collect::<Vec<_>>());
-    patterns.extend(base_patterns.iter().map(rotate_matrix_180_degrees).collect::<Vec<_>>());
-    patterns.extend(base_patterns.iter().map(rotate_matrix_270_degrees).collect::<Vec<_>>());
+struct Day4;
 
-    let rows = data.len();
-    let cols = data[0].len();
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = Vec<Vec<char>>;
 
-    let mut count = 0;
-    for pattern in patterns {
-        let pat_rows = pattern.len();
-        let pat_cols = pattern[0].len();
-        for row in 0..rows {
-            for col in 0..cols {
-                if matches_pattern(&data, &pattern, row, col, rows, cols, pat_rows, pat_cols) {
-                    count += 1;
-                }
-            }
-        }
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.lines().map(|line| line.trim().chars().collect()).collect())
     }
-    let answer = count;
-    println!("Answer: {answer}");
-    Ok(())
-}
 
-fn matches_pattern(
-    grid: &Vec<Vec<char>>,
-    pattern: &Vec<Vec<Option<char>>>,
-    start_row: usize,
-    start_col: usize,
-    rows: usize,
-    cols: usize,
-    pat_rows: usize,
-    pat_cols: usize,
-) -> bool {
-    if start_row + pat_rows > rows || start_col + pat_cols > cols {
-        return false; // Out of bounds for the pattern
+    fn part2(grid: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(find_pattern(grid, &PATTERN, Some(&Transform::ROTATIONS)).len().into())
     }
+}
 
-    for pr in 0..pat_rows {
-        for pc in 0..pat_cols {
-            let pat_char = pattern[pr][pc];
-            let grid_char = grid[start_row + pr][start_col + pc];
-
-            if let Some(pat_char) = pat_char {
-                if pat_char == grid_char {
-                    continue;
-                }
-                return false;
-            }
-        }
-    }
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day4>(Part::Two);
+}
 
-    true
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
 
 #[cfg(test)]
 mod tests {
+    use advent_of_code::utils::matrix::{assert_matrix_eq, PatternSet};
     use advent_of_code::utils::string::{deformat_string, StringToCharsMatrix};
     use super::*;
 
@@ -112,15 +71,7 @@ mod tests {
         let grid = input.to_chars_matrix();
         let expected = expected.to_chars_matrix();
 
-        let base_patterns = vec![PATTERN.clone()];
-        let mut patterns = vec![];
-        patterns.extend(base_patterns.clone());
-        patterns.extend(base_patterns.iter().map(rotate_matrix_90_degrees).collect::<Vec<_>>());
-        patterns.extend(base_patterns.iter().map(rotate_matrix_180_degrees).collect::<Vec<_>>());
-        patterns.extend(base_patterns.iter().map(rotate_matrix_270_degrees).collect::<Vec<_>>());
-
-        let rows = grid.len();
-        let cols = grid[0].len();
+        let patterns = PatternSet::new(&[PATTERN.clone()], Some(&Transform::ROTATIONS));
 
         let mut match_visualization_data = grid.clone()
             .into_iter()
@@ -132,28 +83,14 @@ mod tests {
             .collect::<Vec<_>>();
 
         let mut count = 0;
-        for pattern in patterns {
-            let pat_rows = pattern.len();
-            let pat_cols = pattern[0].len();
-            for row in 0..rows {
-                for col in 0..cols {
-                    if matches_pattern(&grid, &pattern, row, col, rows, cols, pat_rows, pat_cols) {
-                        count += 1;
-                        for (y, line) in pattern.iter().enumerate() {
-                            for (x, char) in line.iter().enumerate() {
-                                if char.is_none() {
-                                    continue;
-                                }
-                                let y = row + y;
-                                let x = col + x;
-                                match_visualization_data[y][x] = grid[y][x];
-                            }
-                        }
-                    }
-                }
+        for found in patterns.find(&grid) {
+            count += 1;
+            for pos in patterns.matched_cells(&found) {
+                let (x, y) = (pos.x as usize, pos.y as usize);
+                match_visualization_data[y][x] = grid[y][x];
             }
         }
-        assert_eq!(match_visualization_data, expected);
+        assert_matrix_eq(&match_visualization_data, &expected);
         assert_eq!(count, 9);
         Ok(())
     }
@@ -1,27 +1,44 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use thiserror::Error;
-use advent_of_code::read_input_lines;
+use anyhow::bail;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 
-fn main() {
-    let lines = read_input_lines(5).map(|line| line.expect("failed to read input"));
-    let (rules, updates) = parse_input(lines);
+struct Day5;
 
-    let mut sum = 0;
-    for update in updates {
-        if validate_update(&rules, &update).is_ok() {
-            if update.len() == 0 || update.len() % 2 == 0 {
-                panic!("expected odd length update, got: {update:?}");
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    type Input = (Rules, Updates);
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input.lines()))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> anyhow::Result<Answer> {
+        let mut sum = 0;
+        for update in updates {
+            if validate_update(rules, &update).is_ok() {
+                if update.len() == 0 || update.len() % 2 == 0 {
+                    bail!("expected odd length update, got: {update:?}");
+                }
+                let mid_ix = update.len() / 2;
+                let Some(mid_page) = update.get(mid_ix) else {
+                    unreachable!()
+                };
+                sum += mid_page;
             }
-            let mid_ix = update.len() / 2;
-            let Some(mid_page) = update.get(mid_ix) else {
-                unreachable!()
-            };
-            sum += mid_page;
         }
+        Ok(sum.into())
     }
-    let answer = sum;
-    println!("Answer: {answer}");
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day5>(Part::One);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
 
 type Rules = HashMap<u32, HashSet<u32>>;
@@ -1,30 +1,49 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use thiserror::Error;
-use advent_of_code::read_input_lines;
+use tracing::error;
+use anyhow::bail;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 
-fn main() {
-    let lines = read_input_lines(5).map(|line| line.expect("failed to read input"));
-    let (rules, updates) = parse_input(lines);
+struct Day5;
 
-    let mut sum = 0;
-    for mut update in updates {
-        if let Err(err) = validate_update(&rules, &update) {
-            if update.len() == 0 || update.len() % 2 == 0 {
-                panic!("expected odd length update, got: {update:?}");
-            }
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    type Input = (Rules, Updates);
 
-            fix_update(&rules, &mut update);
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input.lines()))
+    }
 
-            let mid_ix = update.len() / 2;
-            let Some(mid_page) = update.get(mid_ix) else {
-                unreachable!()
-            };
-            sum += mid_page;
+    fn part2((rules, updates): &Self::Input) -> anyhow::Result<Answer> {
+        let mut sum = 0;
+        for update in updates {
+            let mut update = update.clone();
+            if validate_update(rules, &update).is_err() {
+                if update.len() == 0 || update.len() % 2 == 0 {
+                    bail!("expected odd length update, got: {update:?}");
+                }
+
+                fix_update(rules, &mut update);
+
+                let mid_ix = update.len() / 2;
+                let Some(mid_page) = update.get(mid_ix) else {
+                    unreachable!()
+                };
+                sum += mid_page;
+            }
         }
+        Ok(sum.into())
     }
-    let answer = sum;
-    println!("Answer: {answer}");
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day5>(Part::Two);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
 
 type Rules = HashMap<u32, HashSet<u32>>;
@@ -138,18 +157,16 @@ fn fix_update(rules: &Rules, update: &mut Update) {
         update.insert(err.page_ix, page);
         max_iterations = max_iterations.saturating_sub(1);
         if max_iterations == 0 {
-            println!("original_update: {original_update:?}");
-            println!();
-            println!("rules:");
+            error!("original_update: {original_update:?}");
+            error!("rules:");
             for page in update.iter() {
-                println!("{page}: {:?}", rules.get(&page).map(|rule| {
+                error!("{page}: {:?}", rules.get(&page).map(|rule| {
                     let mut filtered_after = rule.iter().filter(|&page| update.contains(page)).collect::<Vec<_>>();
                     filtered_after.sort();
                     filtered_after
                 }));
             }
-            println!();
-            println!("last validation error: {err:?}");
+            error!("last validation error: {err:?}");
             panic!("max iterations reached! {update:?}")
         }
     }
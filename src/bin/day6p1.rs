@@ -1,19 +1,37 @@
 use std::collections::HashSet;
-use advent_of_code::read_input_lines;
-
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let board = read_input_lines(6).map(|line| line.expect("failed to read input"));
-    let board = board.map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
-    let Some(mut walker) = GuardWalker::init(&board) else {
-        panic!("failed to find start pos");
-    };
-    loop {
-        if walker.move_forward(&board).is_none() {
-            break;
+use anyhow::bail;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+
+struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    type Input = Board;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.lines().map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>())
+    }
+
+    fn part1(board: &Self::Input) -> anyhow::Result<Answer> {
+        let Some(mut walker) = GuardWalker::init(board) else {
+            bail!("failed to find start pos");
+        };
+        loop {
+            if walker.move_forward(board).is_none() {
+                break;
+            }
         }
+        Ok(walker.seen.len().into())
     }
-    println!("Answer: {}", walker.seen.len());
-    Ok(())
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day6>(Part::One);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -127,7 +145,7 @@ impl GuardWalker {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Direction, Pos};
+    use super::{Direction, Pos};
 
     #[test]
     fn direction_turn_test() {
@@ -1,40 +1,97 @@
 use std::collections::{HashMap, HashSet};
 use thiserror::Error;
-use advent_of_code::read_input_lines;
+use anyhow::bail;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+use advent_of_code::utils::matrix::{parse_grid, Glyph, RenderGrid};
+use advent_of_code::utils::recorder::{Recorder, Replay};
+use glam::UVec2;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let board = read_input_lines(6).map(|line| line.expect("failed to read input"));
-    let board = board.map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
-    let Some(mut walker) = GuardWalker::init(&board) else {
-        panic!("failed to find start pos");
-    };
-    loop {
-        match walker.move_forward(&board) {
-            Ok(None) => break,
-            Err(MoveError::Looped) => { panic!("looped"); },
-            _ => {}
-        }
+struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    type Input = Board;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let board = parse_grid(input, |_, c| match c {
+            '.' | '#' | '^' => Ok(c),
+            _ => Err("expected '.', '#' or '^'"),
+        })?;
+        Ok(board.to_matrix())
     }
-    println!("finding potential loops");
-    let mut blocking_count = 0;
-    'outer: for (pos, ..) in walker.seen.into_iter().filter(|(pos, _)| pos != &walker.start_pos) {
-        let mut board = board.clone();
-        board[pos.y][pos.x] = '#';
-        let Some(mut walker) = GuardWalker::init(&board) else {
-            panic!("failed to find start pos");
+
+    fn part2(board: &Self::Input) -> anyhow::Result<Answer> {
+        let Some(mut walker) = GuardWalker::init(board) else {
+            bail!("failed to find start pos");
         };
         loop {
-            match walker.move_forward(&board) {
-                Ok(None) => { continue 'outer; },
-                Err(MoveError::Looped) => {
-                    blocking_count += 1;
-                    continue 'outer;
-                },
+            match walker.move_forward(board) {
+                Ok(None) => break,
+                Err(MoveError::Looped) => bail!("looped"),
                 _ => {}
             }
         }
+        let mut blocking_count = 0;
+        'outer: for (pos, ..) in walker.seen.into_iter().filter(|(pos, _)| pos != &walker.start_pos) {
+            let mut board = board.clone();
+            board[pos.y][pos.x] = '#';
+            let Some(mut walker) = GuardWalker::init(&board) else {
+                bail!("failed to find start pos");
+            };
+            loop {
+                match walker.move_forward(&board) {
+                    Ok(None) => { continue 'outer; },
+                    Err(MoveError::Looped) => {
+                        blocking_count += 1;
+                        continue 'outer;
+                    },
+                    _ => {}
+                }
+            }
+        }
+        Ok(blocking_count.into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day6>(Part::Two);
+}
+
+/// `--play [fps]` or `--cast <path> [fps]` replays the guard's walk instead of solving
+fn main() -> anyhow::Result<()> {
+    if let Some(replay) = Replay::from_args(std::env::args().skip(1)) {
+        let board = Day6::parse(&advent_of_code::read_input(Day6::DAY)?)?;
+        let mut recorder = Recorder::new().every(10);
+        record_walk(&board, &mut recorder)?;
+        replay.run(&recorder)?;
+        return Ok(());
+    }
+    advent_of_code::solution::run_main(register)
+}
+
+/// the board after every step of the walk, visited cells as `X` and the guard pointing where it faces
+fn record_walk(board: &Board, recorder: &mut Recorder) -> anyhow::Result<()> {
+    let Some(mut walker) = GuardWalker::init(board) else {
+        bail!("failed to find start pos");
+    };
+    let frame = |walker: &GuardWalker| {
+        let to_uvec2 = |pos: &Pos| UVec2::new(pos.x as u32, pos.y as u32);
+        board
+            .render()
+            .highlight(walker.seen.keys().map(to_uvec2), 'X')
+            .cursor(to_uvec2(&walker.current_pos), walker.direction.glyph())
+            .to_string()
+    };
+    recorder.record(frame(&walker));
+    loop {
+        match walker.move_forward(board) {
+            Ok(Some(_)) => recorder.record_with(|| frame(&walker)),
+            Ok(None) => break,
+            Err(MoveError::Looped) => bail!("looped"),
+        }
     }
-    println!("Answer: {}", blocking_count);
+    recorder.record_final(frame(&walker));
     Ok(())
 }
 
@@ -102,6 +159,17 @@ impl Direction {
     }
 }
 
+impl Glyph for Direction {
+    fn glyph(&self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 enum MoveError {
     #[error("looped")]
@@ -163,7 +231,8 @@ impl GuardWalker {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Direction, Pos};
+    use advent_of_code::utils::string::deformat_string;
+    use super::*;
 
     #[test]
     fn direction_turn_test() {
@@ -178,4 +247,30 @@ mod tests {
         assert_eq!(dir.next(Pos::default(), Pos::default(), Pos::new(1,1)), Some(Pos::new(0,1)));
         assert_eq!(dir.next(Pos::default(), Pos::default(), Pos::default()), None);
     }
+
+    #[test]
+    fn record_walk_test() -> anyhow::Result<()> {
+        let board = Day6::parse(&deformat_string("
+            ....#.....
+            .........#
+            ..........
+            ..#.......
+            .......#..
+            ..........
+            .#..^.....
+            ........#.
+            #.........
+            ......#...
+        "))?;
+        let mut recorder = Recorder::new();
+        record_walk(&board, &mut recorder)?;
+        assert_eq!(recorder.len(), recorder.steps());
+        let first = &recorder.frames()[0].text;
+        assert_eq!(first.lines().nth(6), Some(".#..^....."));
+        // 41 visited cells, the guard stands on one of them
+        let last = &recorder.frames().last().unwrap().text;
+        assert_eq!(last.matches('X').count(), 40);
+        assert_eq!(last.lines().last(), Some("......#v.."));
+        Ok(())
+    }
 }
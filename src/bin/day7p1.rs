@@ -1,22 +1,38 @@
 use std::collections::VecDeque;
-use std::error::Error;
 use std::fmt::Debug;
 use std::num::ParseIntError;
 use std::str::FromStr;
 use thiserror::Error;
-use advent_of_code::read_input_lines;
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let lines = read_input_lines(7).map(|line| line.expect("failed to read input"));
-    let mut total = 0;
-    for line in lines {
-        let parse_line_result = parse_line(&line)?;
-        if let Some(calibrated_total) = calibrate_equation(parse_line_result) {
-            total += calibrated_total;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+
+struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    type Input = Vec<ParseLineResult>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.lines().map(parse_line).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(equations: &Self::Input) -> anyhow::Result<Answer> {
+        let mut total = 0;
+        for parse_line_result in equations {
+            if let Some(calibrated_total) = calibrate_equation(parse_line_result.clone()) {
+                total += calibrated_total;
+            }
         }
+        Ok(total.into())
     }
-    println!("Answer: {}", total);
-    Ok(())
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day7>(Part::One);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -70,7 +86,7 @@ fn calibrate_equation(parse_line_result: ParseLineResult) -> Option<i64> {
     let operator_slots = parse_line_result.numbers.len().saturating_sub(1);
     // single number
     if operator_slots == 0 {
-        if parse_line_result.total == parse_line_result.numbers.iter().sum() {
+        if parse_line_result.total == parse_line_result.numbers.iter().sum::<i64>() {
             return Some(parse_line_result.total);
         }
         return None;
@@ -1,7 +1,9 @@
 use std::collections::{HashMap, HashSet};
 use std::ops::{Add, Neg, Sub};
 use itertools::Itertools;
-use advent_of_code::read_input_lines;
+use anyhow::bail;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 use advent_of_code::utils::string::deformat_string;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -99,73 +101,63 @@ fn mark_seen(data: &Data, pos: Pos<i32>, seen: &mut SeenMap) {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let lines = read_input_lines(8).map(|line| line.expect("failed to read input"));
-    // et lines = deformat_string("\
-    //    ............
-    //    ........0...
-    //    .....0......
-    //    .......0....
-    //    ....0.......
-    //    ......A.....
-    //    ............
-    //    ............
-    //    ........A...
-    //    .........A..
-    //    ............
-    //    ............
-    // );
-    // et lines = lines.lines();
-    let mut data: Data = vec![];
-    for line in lines {
-        let mut row = vec![];
-        for char in line.chars() {
-            let col = if char == '.' {
-                Marker::default()
-            } else {
-                Marker::antenna(char)
-            };
-            row.push(col);
+struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    type Input = Data;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut data: Data = vec![];
+        for line in input.lines() {
+            let mut row = vec![];
+            for char in line.chars() {
+                let col = if char == '.' {
+                    Marker::default()
+                } else {
+                    Marker::antenna(char)
+                };
+                row.push(col);
+            }
+            data.push(row);
         }
-        data.push(row);
+        Ok(data)
     }
 
-    let height = data.len() as i32;
-    let width = {
-        let row_lens = data.iter().map(|row| row.len());
-        let width = row_lens.clone().max().unwrap_or_default();
-        if width == 0 || row_lens.min().unwrap_or_default() != width {
-            panic!("missing columns");
-        }
-        width as i32
-    };
-
-    let mut seen = SeenMap::new();
-    // row/col
-    for y in 0..height {
-        for x in 0..width {
-            mark_seen(&data, Pos::new(x, y), &mut seen);
-        }
-    }
-    process_seen(&mut data, seen);
-
-    let mut anti_node_count = 0;
-    for row in data.into_iter() {
-        for col in row.into_iter() {
-            if let Some(char) = col.antenna {
-                anti_node_count += 1;
-                print!("{char}");
-            } else if col.anti_node {
-                anti_node_count += 1;
-                print!("#");
-            } else {
-                print!(".");
+    fn part2(data: &Self::Input) -> anyhow::Result<Answer> {
+        let mut data = data.clone();
+        let height = data.len() as i32;
+        let width = {
+            let row_lens = data.iter().map(|row| row.len());
+            let width = row_lens.clone().max().unwrap_or_default();
+            if width == 0 || row_lens.min().unwrap_or_default() != width {
+                bail!("missing columns");
+            }
+            width as i32
+        };
+
+        let mut seen = SeenMap::new();
+        // row/col
+        for y in 0..height {
+            for x in 0..width {
+                mark_seen(&data, Pos::new(x, y), &mut seen);
             }
         }
-        println!();
+        process_seen(&mut data, seen);
+
+        let anti_node_count = data
+            .iter()
+            .flatten()
+            .filter(|col| col.antenna.is_some() || col.anti_node)
+            .count();
+        Ok(anti_node_count.into())
     }
+}
 
-    println!("Answer: {}", anti_node_count);
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day8>(Part::Two);
+}
 
-    Ok(())
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
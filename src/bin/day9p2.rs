@@ -3,19 +3,40 @@ use std::fmt::{Display, Formatter, Write};
 use itertools::{Itertools};
 use log::trace;
 use thiserror::Error;
-use advent_of_code::read_input_lines;
-
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut input = read_input_lines(9);
-    let line = input.next().expect("input empty")?;
-    let disk_map = DeflatedDiskMap::try_from(line)?.inflate();
-    assert!(input.next().is_none(), "input expected to be single line");
-    // println!("Disk map: {disk_map}");
-    let compact_disk_map = disk_map.compact();
-    // println!("Compact Disk map: {compact_disk_map}");
-    let checksum = compact_disk_map.checksum();
-    println!("Checksum: {checksum}");
-    Ok(())
+use anyhow::{bail, ensure};
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
+
+struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    type Input = DiskMap;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut lines = input.lines();
+        let Some(line) = lines.next() else {
+            bail!("input empty");
+        };
+        let disk_map = DeflatedDiskMap::try_from(line)?.inflate();
+        ensure!(lines.next().is_none(), "input expected to be single line");
+        Ok(disk_map)
+    }
+
+    fn part2(disk_map: &Self::Input) -> anyhow::Result<Answer> {
+        // println!("Disk map: {disk_map}");
+        let compact_disk_map = disk_map.clone().compact();
+        // println!("Compact Disk map: {compact_disk_map}");
+        Ok(compact_disk_map.checksum().into())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day9>(Part::Two);
+}
+
+fn main() -> anyhow::Result<()> {
+    advent_of_code::solution::run_main(register)
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -564,7 +585,7 @@ mod tests {
     }
     #[test]
     fn test_checksum() -> Result<(), Box<dyn std::error::Error>> {
-        assert_eq!(DiskMap::try_from("11122")?.compact().checksum(), vec![1 * 0, 1 * 1, 1 * 2, 2 * 3, 2 * 4].into_iter().sum());
+        assert_eq!(DiskMap::try_from("11122")?.compact().checksum(), vec![1 * 0, 1 * 1, 1 * 2, 2 * 3, 2 * 4].into_iter().sum::<usize>());
         let disk_map = DeflatedDiskMap::try_from("2333133121414131402")?.inflate();
         println!("initial: {}", disk_map.as_string());
         let disk_map = disk_map.compact();
@@ -1,9 +1,12 @@
-/// incorrect
+//! incorrect
 
 use std::iter::Rev;
 use std::ops::Range;
 use std::slice::Iter;
 use regex::{Match, Regex};
+use tracing::{debug, trace};
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 
 pub trait CharsToString {
     fn chars_to_string(&self) -> String;
@@ -34,24 +37,24 @@ fn check_string(regex: &Regex, s: &Vec<char>) -> usize {
     let points = matches.map(extract_range).collect::<Vec<_>>();
     let count = points.len();
 
-    println!("{string}");
+    trace!("{string}");
     for point in points {
-        println!("{:<spaces$}{:><width$}", "", "", spaces=point.start, width=point.end-point.start);
+        trace!("{:<spaces$}{:><width$}", "", "", spaces=point.start, width=point.end-point.start);
     }
 
     let reverse_string = &s.iter().rev().chars_to_string();
-    let reverse_matches = regex.find_iter(&reverse_string);
+    let reverse_matches = regex.find_iter(reverse_string);
     let reverse_points = reverse_matches.map(extract_range).collect::<Vec<_>>();
     let reverse_count = reverse_points.len();
 
     for point in reverse_points {
-        println!("{:<spaces$}{:<<width$}", "", "", spaces=string.len()-point.end, width=point.end-point.start);
+        trace!("{:<spaces$}{:<<width$}", "", "", spaces=string.len()-point.end, width=point.end-point.start);
     }
 
     count + reverse_count
 }
 
-fn get_main_diagonals<T>(matrix: &Vec<Vec<T>>) -> Vec<Vec<T>>
+fn get_main_diagonals<T>(matrix: &[Vec<T>]) -> Vec<Vec<T>>
 where
     T: Clone,
 {
@@ -80,7 +83,7 @@ where
     diagonals
 }
 
-fn get_anti_diagonals<T>(matrix: &Vec<Vec<T>>) -> Vec<Vec<T>>
+fn get_anti_diagonals<T>(matrix: &[Vec<T>]) -> Vec<Vec<T>>
 where
     T: Clone,
 {
@@ -115,56 +118,62 @@ fn display(matrix: &[Vec<char>]) {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let lines = advent_of_code::read_input_lines(4);
-    let mut result = 0;
-    let mut data = vec![];
-    for input_line in lines {
-        let input_line = input_line?;
-        let input_line = input_line.trim();
-        data.push(input_line.chars().collect::<Vec<_>>());
-    }
-    let height = data.len();
-    let width = data.first().unwrap().len();
-    let xmas_regex = Regex::new("XMAS").expect("invalid regex");
-
-    println!("\n--- rows ---");
-    let rows = data.clone();
-    display(&rows);
-    for row in rows {
-        result += check_string(&xmas_regex, &row);
-    }
+struct Day4;
 
-    println!("\n--- cols ---");
-    let cols = (0..width)
-        .map(|x| {
-            (0..height)
-                .map(|y| data[y][x])
-                .collect::<Vec<char>>()
-        })
-        .collect::<Vec<_>>();
-    display(&cols);
-    for col in cols {
-        result += check_string(&xmas_regex, &col);
-    }
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = Vec<Vec<char>>;
 
-    println!("\n--- anti ---");
-    let diags = get_main_diagonals(&data);
-    display(&diags);
-    for diag in diags {
-        result += check_string(&xmas_regex, &diag);
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.lines().map(|line| line.trim().chars().collect()).collect())
     }
 
-    println!("\n--- anti_diags ---");
-    let anti_diags = get_anti_diagonals(&data);
-    display(&anti_diags);
-    for diag in anti_diags {
-        result += check_string(&xmas_regex, &diag);
+    fn part1(data: &Self::Input) -> anyhow::Result<Answer> {
+        let mut result = 0;
+        let height = data.len();
+        let width = data.first().map_or(0, Vec::len);
+        let xmas_regex = Regex::new("XMAS").expect("invalid regex");
+
+        debug!("rows");
+        let rows = data.clone();
+        display(&rows);
+        for row in rows {
+            result += check_string(&xmas_regex, &row);
+        }
+
+        debug!("cols");
+        let cols = (0..width)
+            .map(|x| {
+                (0..height)
+                    .map(|y| data[y][x])
+                    .collect::<Vec<char>>()
+            })
+            .collect::<Vec<_>>();
+        display(&cols);
+        for col in cols {
+            result += check_string(&xmas_regex, &col);
+        }
+
+        debug!("diagonals");
+        let diags = get_main_diagonals(data);
+        display(&diags);
+        for diag in diags {
+            result += check_string(&xmas_regex, &diag);
+        }
+
+        debug!("anti diagonals");
+        let anti_diags = get_anti_diagonals(data);
+        display(&anti_diags);
+        for diag in anti_diags {
+            result += check_string(&xmas_regex, &diag);
+        }
+
+        Ok(result.into())
     }
+}
 
-    let answer = result;
-    println!("Answer: {answer}"); // should be 18
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.register_variant::<Day4>(Part::One, "a");
 }
 
 /*
@@ -1,7 +1,9 @@
-/// correct
-/// TODO: fix/cleanup
+//! correct
+//! TODO: fix/cleanup
 
 use std::collections::VecDeque;
+use advent_of_code::registry::Registry;
+use advent_of_code::solution::{Answer, Part, Solution};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 enum Major {
@@ -125,11 +127,7 @@ impl Iterator for MatrixMajorIterator {
             *iy += 1; // Move to the next row/column
         }
 
-        if let Some(coord)  = coord {
-            Some((coord, is_start))
-        } else {
-            None
-        }
+        coord.map(|coord| (coord, is_start))
     }
 }
 
@@ -168,11 +166,7 @@ impl DoubleEndedIterator for MatrixMajorIterator {
             *iy = iy.saturating_sub(1); // Move to the previous row/column
         }
 
-        if let Some(coord) = coord {
-            Some((coord, is_start))
-        } else {
-            None
-        }
+        coord.map(|coord| (coord, is_start))
     }
 }
 
@@ -339,99 +333,106 @@ impl<T: Default + PartialEq> RollingWindowWithCoords<T> {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let lines = advent_of_code::read_input_lines(4);
-    let mut data = vec![];
-    for input_line in lines {
-        let input_line = input_line?;
-        let input_line = input_line.trim();
-        data.push(input_line.chars().collect::<Vec<_>>());
-    }
-    let height = data.len();
-    let width = data.first().unwrap().len();
-
-    fn update<T>(output: &mut Vec<Vec<T>>, value: T, coord: (usize, usize)) {
-        let (x,y) = coord;
-        let row = output.get_mut(y).unwrap();
-        *row.get_mut(x).unwrap() = value;
-    };
-
-    let needle = "XMAS".chars().collect::<Vec<_>>();
-    let needle_reverse = needle.iter().cloned().rev().collect::<Vec<_>>();
-
-    let mut window = RollingWindowWithCoords::new(needle.len());
-    let mut count = 0;
-
-    let mut process = |data: &mut Vec<Vec<char>>, ((x,y), wrapped)| {
-        if wrapped {
-            window.clear()
-        }
-        let line: &Vec<char> = data.get(y).unwrap();
-        let char = *line.get(x).unwrap();
-        window.push_back(char, (x, y));
-        if window.len() == needle.len() {
-            let consume =
-                if needle.ends_with(&[char]) {
-                    window.ends_with(needle.as_slice())
-                } else if needle_reverse.ends_with(&[char]) {
-                    window.ends_with(needle_reverse.as_slice())
-                } else {
-                    false
-                };
-
-            if consume {
-                count += 1;
-                window.consume();
-            }
-        }
-    };
+struct Day4;
 
-    // println!("Rows");
-    for result in MatrixMajorIterator::new(Major::Row, height, width) {
-        process(&mut data, result);
-    }
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = Vec<Vec<char>>;
 
-    // println!("Cols");
-    for result in MatrixMajorIterator::new(Major::Col, height, width) {
-        process(&mut data, result);
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.lines().map(|line| line.trim().chars().collect()).collect())
     }
 
-    // println!("Diag");
-    for result in MatrixDiagonalCoordIterator::new(height, width, false) {
-        process(&mut data, result);
-    }
+    fn part1(data: &Self::Input) -> anyhow::Result<Answer> {
+        let mut data = data.clone();
+        let height = data.len();
+        let width = data.first().map_or(0, Vec::len);
+
+        fn update<T>(output: &mut [Vec<T>], value: T, coord: (usize, usize)) {
+            let (x,y) = coord;
+            let row = output.get_mut(y).unwrap();
+            *row.get_mut(x).unwrap() = value;
+        };
 
-    fn rotate_matrix_90_degrees<T: Copy + Default>(matrix: Vec<Vec<T>>) -> Vec<Vec<T>> {
-        let rows = matrix.len();
-        let cols = matrix[0].len();
+        let needle = "XMAS".chars().collect::<Vec<_>>();
+        let needle_reverse = needle.iter().cloned().rev().collect::<Vec<_>>();
+
+        let mut window = RollingWindowWithCoords::new(needle.len());
+        let mut count = 0;
 
-        // Create a new matrix with reversed dimensions
-        let mut rotated = vec![vec![T::default(); rows]; cols];
+        let mut process = |data: &mut Vec<Vec<char>>, ((x,y), wrapped)| {
+            if wrapped {
+                window.clear()
+            }
+            let line: &Vec<char> = data.get(y).unwrap();
+            let char = *line.get(x).unwrap();
+            window.push_back(char, (x, y));
+            if window.len() == needle.len() {
+                let consume =
+                    if needle.ends_with(&[char]) {
+                        window.ends_with(needle.as_slice())
+                    } else if needle_reverse.ends_with(&[char]) {
+                        window.ends_with(needle_reverse.as_slice())
+                    } else {
+                        false
+                    };
 
-        // Populate the rotated matrix
-        for i in 0..rows {
-            for j in 0..cols {
-                rotated[j][rows - i - 1] = matrix[i][j];
+                if consume {
+                    count += 1;
+                    window.consume();
+                }
             }
+        };
+
+        // println!("Rows");
+        for result in MatrixMajorIterator::new(Major::Row, height, width) {
+            process(&mut data, result);
         }
 
-        rotated
-    }
+        // println!("Cols");
+        for result in MatrixMajorIterator::new(Major::Col, height, width) {
+            process(&mut data, result);
+        }
 
-    data = rotate_matrix_90_degrees(data);
-    for result in MatrixDiagonalCoordIterator::new(height, width, false) {
-        process(&mut data, result);
-    }
+        // println!("Diag");
+        for result in MatrixDiagonalCoordIterator::new(height, width, false) {
+            process(&mut data, result);
+        }
+
+        fn rotate_matrix_90_degrees<T: Copy + Default>(matrix: Vec<Vec<T>>) -> Vec<Vec<T>> {
+            let rows = matrix.len();
+            let cols = matrix[0].len();
 
-    // TODO: broken
-    // println!("Diag Anti");
-    // for result in MatrixDiagonalCoordIterator::new(height, width, true) {
-    //     process(&mut data, result);
-    // }
+            // Create a new matrix with reversed dimensions
+            let mut rotated = vec![vec![T::default(); rows]; cols];
+
+            // Populate the rotated matrix
+            for i in 0..rows {
+                for j in 0..cols {
+                    rotated[j][rows - i - 1] = matrix[i][j];
+                }
+            }
+
+            rotated
+        }
+
+        data = rotate_matrix_90_degrees(data);
+        for result in MatrixDiagonalCoordIterator::new(height, width, false) {
+            process(&mut data, result);
+        }
+
+        // TODO: broken
+        // println!("Diag Anti");
+        // for result in MatrixDiagonalCoordIterator::new(height, width, true) {
+        //     process(&mut data, result);
+        // }
+
+        Ok(count.into())
+    }
+}
 
-    let answer = count;
-    println!("Answer: {answer}");
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.register_variant::<Day4>(Part::One, "c");
 }
 
 #[cfg(test)]
@@ -620,7 +621,7 @@ mod tests {
         let mut data: Vec<Vec<char>> = data.map(|line| line.to_vec()).into_iter().collect::<Vec<_>>();
 
 
-        fn update<T>(output: &mut Vec<Vec<T>>, value: T, coord: (usize, usize)) {
+        fn update<T>(output: &mut [Vec<T>], value: T, coord: (usize, usize)) {
             let (x,y) = coord;
             let row = output.get_mut(y).unwrap();
             *row.get_mut(x).unwrap() = value;
@@ -0,0 +1,425 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+use crate::input::{InputError, InputResolver};
+use crate::solution::Part;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// overrides [`DEFAULT_BASE_URL`], i.e. to point at a local stub server
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// value of the `session` cookie from a logged in browser
+pub const SESSION_ENV: &str = "AOC_SESSION";
+const USER_AGENT: &str = "github.com/StrikeForceZero/advent_of_code";
+const SUBMISSIONS_FILE: &str = "submissions.json";
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("missing session token, set {SESSION_ENV}")]
+    MissingSession,
+    #[error("request to {url} failed with status {status}")]
+    Status { url: String, status: u16 },
+    #[error("request to {url} failed: {source}")]
+    Transport { url: String, source: Box<ureq::Transport> },
+    #[error("failed to read response from {url}: {source}")]
+    Body { url: String, source: std::io::Error },
+    #[error("failed to access {path:?}: {source}")]
+    Io { path: PathBuf, source: std::io::Error },
+    #[error("invalid submissions state at {path:?}: {source}")]
+    State { path: PathBuf, source: serde_json::Error },
+    #[error(transparent)]
+    Input(#[from] InputError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    /// `hint` is the site's "too high"/"too low" when given
+    Incorrect { hint: Option<String> },
+    /// the answer was already rejected, nothing is sent
+    AlreadyRejected,
+    /// submitting is blocked until the wait is over, nothing is sent when it comes from the local state
+    Cooldown { wait: Duration },
+    AlreadySolved,
+    /// the response didn't match any of the known messages
+    Unknown(String),
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Incorrect { hint: Some(hint) } => write!(f, "incorrect, answer is {hint}"),
+            Self::Incorrect { hint: None } => write!(f, "incorrect"),
+            Self::AlreadyRejected => write!(f, "already rejected, not submitting again"),
+            Self::Cooldown { wait } => write!(f, "on cooldown, wait {}s", wait.as_secs()),
+            Self::AlreadySolved => write!(f, "already solved"),
+            Self::Unknown(response) => write!(f, "unrecognized response: {response}"),
+        }
+    }
+}
+
+lazy_static! {
+    static ref ARTICLE_RE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    static ref TAG_RE: Regex = Regex::new(r"<[^>]+>").unwrap();
+    static ref HINT_RE: Regex = Regex::new(r"your answer is too (high|low)").unwrap();
+    static ref LEFT_TO_WAIT_RE: Regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    static ref PLEASE_WAIT_RE: Regex = Regex::new(r"[Pp]lease wait (one|\d+) minutes?").unwrap();
+}
+
+fn parse_submit_response(html: &str) -> SubmitOutcome {
+    let text = ARTICLE_RE
+        .captures(html)
+        .and_then(|captures| captures.get(1))
+        .map_or(html, |article| article.as_str());
+    let text = TAG_RE.replace_all(text, "");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = HINT_RE.captures(&text).map(|captures| captures[1].to_string());
+        SubmitOutcome::Incorrect { hint }
+    } else if let Some(captures) = LEFT_TO_WAIT_RE.captures(&text) {
+        let minutes = captures.get(1).map_or(0, |minutes| minutes.as_str().parse().unwrap_or(0));
+        let seconds = captures[2].parse().unwrap_or(0);
+        SubmitOutcome::Cooldown { wait: Duration::from_secs(minutes * 60 + seconds) }
+    } else if text.contains("Did you already complete it") {
+        SubmitOutcome::AlreadySolved
+    } else {
+        SubmitOutcome::Unknown(text)
+    }
+}
+
+/// the wait the site asks for after a wrong answer, defaults to a minute
+fn retry_wait(html: &str) -> Duration {
+    let minutes = PLEASE_WAIT_RE
+        .captures(html)
+        .map_or(1, |captures| match &captures[1] {
+            "one" => 1,
+            minutes => minutes.parse().unwrap_or(1),
+        });
+    Duration::from_secs(minutes * 60)
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |now| now.as_secs())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct PartSubmissions {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    wrong: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    correct: Option<String>,
+}
+
+/// what has been submitted for a year, persisted next to the cached inputs
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Submissions {
+    /// unix seconds until which nothing is submitted
+    #[serde(default)]
+    cooldown_until: u64,
+    /// keyed by `dayNpM`
+    #[serde(default)]
+    parts: BTreeMap<String, PartSubmissions>,
+}
+
+impl Submissions {
+    fn load(path: &Path) -> Result<Self, ClientError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(|source| ClientError::State {
+                path: path.to_path_buf(),
+                source,
+            }),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(ClientError::Io { path: path.to_path_buf(), source }),
+        }
+    }
+    fn save(&self, path: &Path) -> Result<(), ClientError> {
+        let contents = serde_json::to_string_pretty(self).map_err(|source| ClientError::State {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        write_file(path, &contents)
+    }
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), ClientError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|source| ClientError::Io { path: parent.to_path_buf(), source })?;
+    }
+    fs::write(path, contents).map_err(|source| ClientError::Io { path: path.to_path_buf(), source })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientConfig {
+    pub base_url: String,
+    pub session: String,
+    pub year: u32,
+    /// inputs are cached as `<cache_dir>/<year>/dayN`, the layout [`InputResolver`] looks for
+    pub cache_dir: PathBuf,
+}
+
+impl ClientConfig {
+    /// uses [`BASE_URL_ENV`], [`SESSION_ENV`] and the resolver's root and year
+    pub fn from_env(resolver: &InputResolver) -> Result<Self, ClientError> {
+        let session = env::var(SESSION_ENV)
+            .ok()
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+            .ok_or(ClientError::MissingSession)?;
+        Ok(Self {
+            base_url: env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+            session,
+            year: resolver.year(),
+            cache_dir: resolver.root().to_path_buf(),
+        })
+    }
+}
+
+/// downloads inputs and submits answers for a single event year
+pub struct PuzzleClient {
+    config: ClientConfig,
+    agent: ureq::Agent,
+}
+
+impl PuzzleClient {
+    pub fn new(config: ClientConfig) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Self { config, agent }
+    }
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}{path}", self.config.base_url.trim_end_matches('/'), self.config.year)
+    }
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        self.config.cache_dir.join(self.config.year.to_string()).join(format!("day{day}"))
+    }
+    fn submissions_path(&self) -> PathBuf {
+        self.config.cache_dir.join(self.config.year.to_string()).join(SUBMISSIONS_FILE)
+    }
+    fn cookie(&self) -> String {
+        format!("session={}", self.config.session)
+    }
+    fn read_response(url: String, result: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+        match result {
+            Ok(response) => response.into_string().map_err(|source| ClientError::Body { url, source }),
+            Err(ureq::Error::Status(status, _)) => Err(ClientError::Status { url, status }),
+            Err(ureq::Error::Transport(transport)) => Err(ClientError::Transport {
+                url,
+                source: Box::new(transport),
+            }),
+        }
+    }
+    /// returns the cached input when present, otherwise downloads and caches it
+    pub fn fetch_input(&self, day: u32) -> Result<String, ClientError> {
+        let path = self.input_path(day);
+        if let Ok(input) = fs::read_to_string(&path) {
+            debug!(?path, "using cached input");
+            return Ok(input);
+        }
+        let url = self.url(&format!("/day/{day}/input"));
+        debug!(url, "fetching input");
+        let result = self.agent.get(&url).set("Cookie", &self.cookie()).call();
+        let input = Self::read_response(url, result)?;
+        write_file(&path, &input)?;
+        Ok(input)
+    }
+    /// loads the input wherever `resolver` finds it, i.e. the flat `inputs/dayN`, and only downloads it when it's missing,
+    /// a missing [`InputResolver::with_source`] override is an error instead
+    pub fn load_or_fetch_input(&self, resolver: &InputResolver, day: u32) -> Result<String, ClientError> {
+        match resolver.load(day, None) {
+            Err(InputError::Missing { .. }) if resolver.source().is_none() => self.fetch_input(day),
+            result => Ok(result?),
+        }
+    }
+    /// submits unless the answer was already rejected or a cooldown is still running
+    pub fn submit(&self, day: u32, part: Part, answer: &str) -> Result<SubmitOutcome, ClientError> {
+        let state_path = self.submissions_path();
+        let mut submissions = Submissions::load(&state_path)?;
+        let key = format!("day{day}p{part}");
+        let previous = submissions.parts.get(&key).cloned().unwrap_or_default();
+        if previous.correct.is_some() {
+            return Ok(SubmitOutcome::AlreadySolved);
+        }
+        if previous.wrong.iter().any(|wrong| wrong == answer) {
+            return Ok(SubmitOutcome::AlreadyRejected);
+        }
+        let now = now();
+        if submissions.cooldown_until > now {
+            return Ok(SubmitOutcome::Cooldown { wait: Duration::from_secs(submissions.cooldown_until - now) });
+        }
+
+        let url = self.url(&format!("/day/{day}/answer"));
+        debug!(url, answer, "submitting");
+        let level = part.to_string();
+        let result = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", level.as_str()), ("answer", answer)]);
+        let html = Self::read_response(url, result)?;
+        let outcome = parse_submit_response(&html);
+        let entry = submissions.parts.entry(key).or_default();
+        match &outcome {
+            SubmitOutcome::Correct => entry.correct = Some(answer.to_string()),
+            SubmitOutcome::Incorrect { .. } => {
+                entry.wrong.push(answer.to_string());
+                submissions.cooldown_until = now + retry_wait(&html).as_secs();
+            }
+            SubmitOutcome::Cooldown { wait } => submissions.cooldown_until = now + wait.as_secs(),
+            _ => {}
+        }
+        submissions.save(&state_path)?;
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use super::*;
+
+    /// serves `responses` in order, one request per connection, and records each request
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap_or(0);
+                    }
+                    request.push_str(&line);
+                }
+                let mut body_bytes = vec![0; content_length];
+                reader.read_exact(&mut body_bytes).unwrap();
+                request.push_str(&String::from_utf8_lossy(&body_bytes));
+                recorded.lock().unwrap().push(request);
+                let response = format!(
+                    "HTTP/1.1 {status} STUB\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len(),
+                );
+                let mut stream = reader.into_inner();
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (base_url, requests)
+    }
+
+    fn client(base_url: String, name: &str) -> PuzzleClient {
+        let cache_dir = env::temp_dir().join(format!("advent_of_code_client_{}_{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+        PuzzleClient::new(ClientConfig {
+            base_url,
+            session: "abc123".to_string(),
+            year: 2024,
+            cache_dir,
+        })
+    }
+
+    #[test]
+    fn fetch_input_test() -> anyhow::Result<()> {
+        let (base_url, requests) = stub_server(vec![(200, "1 2\n3 4\n"), (404, "")]);
+        let client = client(base_url, "fetch");
+        assert_eq!(client.fetch_input(1)?, "1 2\n3 4\n");
+        // served from the cache, the stub would answer 404 otherwise
+        assert_eq!(client.fetch_input(1)?, "1 2\n3 4\n");
+        assert_eq!(fs::read_to_string(client.input_path(1))?, "1 2\n3 4\n");
+        assert!(matches!(client.fetch_input(2), Err(ClientError::Status { status: 404, .. })));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("GET /2024/day/1/input "));
+        assert!(requests[0].contains("session=abc123"));
+        fs::remove_dir_all(&client.config().cache_dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn load_or_fetch_input_test() -> anyhow::Result<()> {
+        let (base_url, requests) = stub_server(vec![(200, "5 6\n")]);
+        let client = client(base_url, "load_or_fetch");
+        let resolver = InputResolver::new(&client.config().cache_dir).with_year(2024);
+        fs::create_dir_all(resolver.root())?;
+        fs::write(resolver.root().join("day1"), "1 2\n")?;
+        assert_eq!(client.load_or_fetch_input(&resolver, 1)?, "1 2\n");
+        assert!(!client.input_path(1).exists());
+        assert_eq!(client.load_or_fetch_input(&resolver, 2)?, "5 6\n");
+        assert_eq!(resolver.load(2, None)?, "5 6\n");
+
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        fs::remove_dir_all(resolver.root())?;
+        Ok(())
+    }
+
+    #[test]
+    fn submit_test() -> anyhow::Result<()> {
+        let (base_url, requests) = stub_server(vec![
+            (200, "<main><article><p>That's not the right answer; your answer is too low. Please wait one minute before trying again.</p></article></main>"),
+            (200, "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>"),
+        ]);
+        let client = client(base_url, "submit");
+        assert_eq!(client.submit(6, Part::One, "41")?, SubmitOutcome::Incorrect { hint: Some("low".to_string()) });
+        assert_eq!(client.submit(6, Part::One, "41")?, SubmitOutcome::AlreadyRejected);
+        assert!(matches!(client.submit(6, Part::One, "42")?, SubmitOutcome::Cooldown { .. }));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        assert!(requests.lock().unwrap()[0].ends_with("level=1&answer=41"));
+
+        // lift the cooldown and try again
+        let path = client.submissions_path();
+        let mut submissions = Submissions::load(&path)?;
+        submissions.cooldown_until = 0;
+        submissions.save(&path)?;
+        assert_eq!(client.submit(6, Part::One, "42")?, SubmitOutcome::Correct);
+        assert_eq!(client.submit(6, Part::One, "42")?, SubmitOutcome::AlreadySolved);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        fs::remove_dir_all(&client.config().cache_dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn parse_submit_response_test() {
+        assert_eq!(
+            parse_submit_response("<article><p>You gave an answer too recently. You have 1m 5s left to wait.</p></article>"),
+            SubmitOutcome::Cooldown { wait: Duration::from_secs(65) },
+        );
+        assert_eq!(
+            parse_submit_response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            SubmitOutcome::AlreadySolved,
+        );
+        assert_eq!(
+            parse_submit_response("<article><p>That's not the right answer.  If you're stuck...</p></article>"),
+            SubmitOutcome::Incorrect { hint: None },
+        );
+        assert_eq!(retry_wait("please wait 5 minutes before trying again"), Duration::from_secs(300));
+        assert_eq!(retry_wait(""), Duration::from_secs(60));
+    }
+}
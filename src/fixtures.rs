@@ -0,0 +1,119 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use lazy_static::lazy_static;
+use regex::Regex;
+use crate::input::{load_input, InputError};
+
+/// default location of the extracted examples, `fixtures/dayN/exampleI` and `fixtures/dayN/answers`
+pub fn fixtures_dir() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"))
+}
+
+const ANSWERS_FILE: &str = "answers";
+
+lazy_static! {
+    static ref PRE_CODE_RE: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    static ref EM_CODE_RE: Regex = Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap();
+    static ref TAG_RE: Regex = Regex::new(r"<[^>]+>").unwrap();
+}
+
+fn unescape(html: &str) -> String {
+    TAG_RE
+        .replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// the example blocks and emphasized answers of a puzzle description, in page order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuzzleExamples {
+    pub examples: Vec<String>,
+    pub answers: Vec<String>,
+}
+
+impl PuzzleExamples {
+    /// extracts `<pre><code>` blocks as examples and `<code><em>` spans as answers from a saved puzzle page
+    pub fn from_html(html: &str) -> Self {
+        let examples = PRE_CODE_RE
+            .captures_iter(html)
+            .map(|captures| unescape(&captures[1]))
+            .collect();
+        let answers = EM_CODE_RE
+            .captures_iter(html)
+            .filter_map(|captures| captures.get(1).or(captures.get(2)))
+            .map(|answer| unescape(answer.as_str()))
+            .collect();
+        Self { examples, answers }
+    }
+    /// writes `<dir>/dayN/exampleI` (starting at 1) and `<dir>/dayN/answers` with one answer per line,
+    /// replacing whatever was extracted for the day before
+    pub fn write(&self, dir: impl AsRef<Path>, day: u32) -> std::io::Result<PathBuf> {
+        let day_dir = dir.as_ref().join(format!("day{day}"));
+        if day_dir.exists() {
+            fs::remove_dir_all(&day_dir)?;
+        }
+        fs::create_dir_all(&day_dir)?;
+        for (ix, example) in self.examples.iter().enumerate() {
+            fs::write(day_dir.join(format!("example{}", ix + 1)), example)?;
+        }
+        let answers = self.answers.iter().map(|answer| format!("{answer}\n")).collect::<String>();
+        fs::write(day_dir.join(ANSWERS_FILE), answers)?;
+        Ok(day_dir)
+    }
+}
+
+/// loads `fixtures/dayN/exampleI`, `index` starts at 1 like the file names
+pub fn load_example(day: u32, index: usize) -> Result<String, InputError> {
+    load_input(day, fixtures_dir().join(format!("day{day}")).join(format!("example{index}")))
+}
+
+/// loads the emphasized answers extracted for `day`, in page order
+pub fn load_example_answers(day: u32) -> Result<Vec<String>, InputError> {
+    let answers = load_input(day, fixtures_dir().join(format!("day{day}")).join(ANSWERS_FILE))?;
+    Ok(answers.lines().map(String::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = r#"
+        <article class="day-desc"><h2>--- Day 6: Guard Gallivant ---</h2>
+        <p>For example:</p>
+        <pre><code>....#.....
+        .........#
+        ....^.....
+        </code></pre>
+        <p>In this example, the guard will visit <code><em>41</em></code> distinct positions.</p>
+        <pre><code>a &lt;b&gt; &amp; <em>c</em>
+        </code></pre>
+        <p>Answer <em><code>6</code></em></p>
+        </article>
+    "#;
+
+    #[test]
+    fn from_html_test() {
+        let examples = PuzzleExamples::from_html(HTML);
+        assert_eq!(examples.examples.len(), 2);
+        assert!(examples.examples[0].starts_with("....#.....\n"));
+        assert!(examples.examples[1].starts_with("a <b> & c\n"));
+        assert_eq!(examples.answers, vec!["41", "6"]);
+    }
+
+    #[test]
+    fn write_test() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("advent_of_code_fixtures_{}", std::process::id()));
+        let day_dir = PuzzleExamples::from_html(HTML).write(&dir, 6)?;
+        assert!(fs::read_to_string(day_dir.join("example1"))?.starts_with("....#"));
+        assert!(day_dir.join("example2").exists());
+        assert_eq!(fs::read_to_string(day_dir.join("answers"))?, "41\n6\n");
+
+        PuzzleExamples { examples: vec!["x".to_string()], answers: vec![] }.write(&dir, 6)?;
+        assert!(!day_dir.join("example2").exists());
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// overrides the directory inputs are resolved from
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+/// selects the event year used for the `inputs/<year>/dayN` layout
+pub const YEAR_ENV: &str = "AOC_YEAR";
+/// reads every day's input from a single path instead, `-` reads it from stdin
+pub const INPUT_ENV: &str = "AOC_INPUT";
+pub const DEFAULT_YEAR: u32 = 2024;
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("day {day}: input not found at {path:?}")]
+    Missing { day: u32, path: PathBuf },
+    #[error("day {day}: failed to read input at {path:?}: {source}")]
+    Unreadable { day: u32, path: PathBuf, source: io::Error },
+    #[error("day {day}: input at {path:?} is not valid utf-8")]
+    NotUtf8 { day: u32, path: PathBuf },
+    #[error("day {day}: input at {path:?} is empty")]
+    Empty { day: u32, path: PathBuf },
+}
+
+impl InputError {
+    pub fn day(&self) -> u32 {
+        match self {
+            Self::Missing { day, .. }
+            | Self::Unreadable { day, .. }
+            | Self::NotUtf8 { day, .. }
+            | Self::Empty { day, .. } => *day,
+        }
+    }
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Missing { path, .. }
+            | Self::Unreadable { path, .. }
+            | Self::NotUtf8 { path, .. }
+            | Self::Empty { path, .. } => path,
+        }
+    }
+}
+
+/// path used in errors for input read from stdin
+pub fn stdin_path() -> PathBuf {
+    PathBuf::from("<stdin>")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn load(&self, day: u32) -> Result<String, InputError> {
+        match self {
+            Self::Path(path) => load_input(day, path),
+            Self::Stdin => load_stdin(day),
+        }
+    }
+}
+
+/// `-` reads from stdin, anything else is a path
+impl FromStr for InputSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Self::Stdin,
+            path => Self::Path(PathBuf::from(path)),
+        })
+    }
+}
+
+/// finds input files under a root directory,
+/// preferring `<root>/<year>/dayN` and falling back to `<root>/dayN`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputResolver {
+    root: PathBuf,
+    year: u32,
+    source: Option<InputSource>,
+}
+
+impl Default for InputResolver {
+    fn default() -> Self {
+        Self::new(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"))
+    }
+}
+
+impl InputResolver {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            year: DEFAULT_YEAR,
+            source: None,
+        }
+    }
+    /// honors [`INPUT_DIR_ENV`], [`YEAR_ENV`] and [`INPUT_ENV`], otherwise the crate's `inputs` dir
+    pub fn from_env() -> Self {
+        let mut resolver = match env::var_os(INPUT_DIR_ENV) {
+            Some(root) => Self::new(root),
+            None => Self::default(),
+        };
+        if let Some(year) = env::var(YEAR_ENV).ok().and_then(|year| year.trim().parse().ok()) {
+            resolver = resolver.with_year(year);
+        }
+        if let Some(source) = env::var(INPUT_ENV).ok().filter(|source| !source.trim().is_empty()) {
+            let Ok(source) = source.trim().parse();
+            resolver = resolver.with_source(source);
+        }
+        resolver
+    }
+    pub fn with_year(mut self, year: u32) -> Self {
+        self.year = year;
+        self
+    }
+    /// `load` reads the default input of every day from `source`, named inputs still come from the root
+    pub fn with_source(mut self, source: InputSource) -> Self {
+        self.source = Some(source);
+        self
+    }
+    pub fn source(&self) -> Option<&InputSource> {
+        self.source.as_ref()
+    }
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+    /// `name` selects an alternate input, i.e. `Some("example2")` resolves `day6.example2`
+    pub fn resolve(&self, day: u32, name: Option<&str>) -> PathBuf {
+        let file_name = match name {
+            Some(name) => format!("day{day}.{name}"),
+            None => format!("day{day}"),
+        };
+        let year_path = self.root.join(self.year.to_string()).join(&file_name);
+        if year_path.is_file() {
+            return year_path;
+        }
+        self.root.join(file_name)
+    }
+    pub fn load(&self, day: u32, name: Option<&str>) -> Result<String, InputError> {
+        match (&self.source, name) {
+            (Some(source), None) => source.load(day),
+            _ => load_input(day, self.resolve(day, name)),
+        }
+    }
+}
+
+/// reads the input for `day` from `path`, treating a whitespace only file as empty
+pub fn load_input(day: u32, path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref().to_path_buf();
+    let bytes = match fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Err(InputError::Missing { day, path }),
+        Err(source) => return Err(InputError::Unreadable { day, path, source }),
+    };
+    decode(day, path, bytes)
+}
+
+pub fn load_stdin(day: u32) -> Result<String, InputError> {
+    let mut bytes = vec![];
+    if let Err(source) = io::stdin().lock().read_to_end(&mut bytes) {
+        return Err(InputError::Unreadable { day, path: stdin_path(), source });
+    }
+    decode(day, stdin_path(), bytes)
+}
+
+fn decode(day: u32, path: PathBuf, bytes: Vec<u8>) -> Result<String, InputError> {
+    let Ok(input) = String::from_utf8(bytes) else {
+        return Err(InputError::NotUtf8 { day, path });
+    };
+    if input.trim().is_empty() {
+        return Err(InputError::Empty { day, path });
+    }
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use super::*;
+
+    fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("advent_of_code_input_{}_{name}", std::process::id()));
+        fs::write(&path, contents).expect("failed to write temp file");
+        path
+    }
+
+    #[test]
+    fn load_input_test() -> anyhow::Result<()> {
+        let path = temp_file("ok", b"1 2\n3 4\n");
+        assert_eq!(load_input(1, &path)?, "1 2\n3 4\n");
+        fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn load_input_errors_test() -> anyhow::Result<()> {
+        let missing = std::env::temp_dir().join("advent_of_code_input_does_not_exist");
+        let err = load_input(3, &missing).unwrap_err();
+        assert!(matches!(err, InputError::Missing { day: 3, .. }));
+        assert_eq!(err.path(), missing);
+
+        let not_utf8 = temp_file("not_utf8", &[0xff, 0xfe, b'\n']);
+        assert!(matches!(load_input(4, &not_utf8), Err(InputError::NotUtf8 { day: 4, .. })));
+        fs::remove_file(not_utf8)?;
+
+        let empty = temp_file("empty", b" \n\n");
+        assert!(matches!(load_input(5, &empty), Err(InputError::Empty { day: 5, .. })));
+        fs::remove_file(empty)?;
+
+        let dir = std::env::temp_dir();
+        let err = load_input(6, &dir).unwrap_err();
+        assert!(matches!(err, InputError::Unreadable { day: 6, .. }));
+        assert_eq!(err.day(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn resolver_test() -> anyhow::Result<()> {
+        let root = std::env::temp_dir().join(format!("advent_of_code_inputs_{}", std::process::id()));
+        fs::create_dir_all(root.join("2023"))?;
+        fs::write(root.join("day1"), "flat")?;
+        fs::write(root.join("2023").join("day1"), "2023")?;
+        fs::write(root.join("day6.example2"), "example")?;
+
+        let resolver = InputResolver::new(&root);
+        assert_eq!(resolver.resolve(1, None), root.join("day1"));
+        assert_eq!(resolver.load(6, Some("example2"))?, "example");
+        assert_eq!(resolver.resolve(2, None), root.join("day2"));
+
+        let resolver = resolver.with_year(2023);
+        assert_eq!(resolver.resolve(1, None), root.join("2023").join("day1"));
+        assert_eq!(resolver.load(1, None)?, "2023");
+        assert_eq!(resolver.resolve(6, Some("example2")), root.join("day6.example2"));
+
+        fs::remove_dir_all(root)?;
+        Ok(())
+    }
+
+    #[test]
+    fn resolver_source_test() -> anyhow::Result<()> {
+        let path = temp_file("source", b"override\n");
+        let root = std::env::temp_dir().join(format!("advent_of_code_inputs_source_{}", std::process::id()));
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("day6.example2"), "example")?;
+
+        let resolver = InputResolver::new(&root).with_source(InputSource::Path(path.clone()));
+        assert_eq!(resolver.load(1, None)?, "override\n");
+        assert_eq!(resolver.load(6, None)?, "override\n");
+        assert_eq!(resolver.load(6, Some("example2"))?, "example");
+
+        fs::remove_file(path)?;
+        fs::remove_dir_all(root)?;
+        Ok(())
+    }
+
+    /// runs itself again in a child process with [`INPUT_ENV`] set to `-` and the input piped to its stdin
+    #[test]
+    fn read_input_stdin_test() -> anyhow::Result<()> {
+        if env::var(INPUT_ENV).as_deref() == Ok("-") {
+            let lines = crate::read_input_lines(1)?.collect::<Vec<_>>();
+            assert_eq!(lines, vec!["1 2", "3 4"]);
+            return Ok(());
+        }
+        let mut child = std::process::Command::new(env::current_exe()?)
+            .args(["input::tests::read_input_stdin_test", "--exact"])
+            .env(INPUT_ENV, "-")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .spawn()?;
+        child.stdin.take().expect("stdin is piped").write_all(b"1 2\n3 4\n")?;
+        let output = child.wait_with_output()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "{stdout}");
+        assert!(stdout.contains("1 passed"), "{stdout}");
+        Ok(())
+    }
+
+    #[test]
+    fn input_source_from_str_test() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!("inputs/day1".parse(), Ok(InputSource::Path(PathBuf::from("inputs/day1"))));
+    }
+}
@@ -1,17 +1,28 @@
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod fixtures;
+pub mod input;
+pub mod logging;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
 pub mod utils;
 
-use std::fs;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+use crate::input::{InputError, InputResolver};
 
-pub fn read_input_lines(day: u32) -> Lines<BufReader<File>> {
-    let input_path = format!("inputs/day{}", day);
-    let input_file = File::open(input_path).expect("File not found");
-    let input_reader = BufReader::new(input_file);
-    input_reader.lines().into_iter()
+pub fn read_input_lines(day: u32) -> Result<std::vec::IntoIter<String>, InputError> {
+    let input = read_input(day)?;
+    Ok(input.lines().map(String::from).collect::<Vec<_>>().into_iter())
 }
 
-pub fn read_input(day: u32) -> std::io::Result<String> {
-    let input_path = format!("inputs/day{}", day);
-    fs::read_to_string(input_path)
+/// reads the input for `day` using [`InputResolver::from_env`], `AOC_INPUT=-` reads it from stdin
+pub fn read_input(day: u32) -> Result<String, InputError> {
+    read_named_input(day, None)
+}
+
+/// reads an alternate input for `day`, i.e. `day6.example2`
+pub fn read_named_input(day: u32, name: Option<&str>) -> Result<String, InputError> {
+    InputResolver::from_env().load(day, name)
 }
@@ -0,0 +1,18 @@
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+/// default verbosity when `RUST_LOG` is not set, keeps runs quiet
+pub const DEFAULT_FILTER: &str = "warn";
+
+/// installs a stderr subscriber driven by `RUST_LOG`,
+/// i.e. `RUST_LOG=info` to time the per-day parse/solve spans or `RUST_LOG=debug` for the solutions' diagnostics
+///
+/// does nothing when a subscriber is already installed
+pub fn init_tracing() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER));
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .try_init();
+}
@@ -0,0 +1,231 @@
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use anyhow::anyhow;
+use tracing::{debug, info_span};
+use crate::solution::{Answer, Part, Solution};
+
+pub type ParsedInput = Box<dyn Any>;
+
+/// type erased [`Solution`] so solutions with different inputs can live in the same [`Registry`]
+pub trait DynSolution: Send + Sync {
+    fn day(&self) -> u32;
+    fn parse(&self, input: &str) -> anyhow::Result<ParsedInput>;
+    fn solve(&self, input: &ParsedInput, part: Part) -> anyhow::Result<Answer>;
+}
+
+struct SolutionHandle<S>(PhantomData<fn() -> S>);
+
+impl<S> DynSolution for SolutionHandle<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+    fn parse(&self, input: &str) -> anyhow::Result<ParsedInput> {
+        Ok(Box::new(S::parse(input)?))
+    }
+    fn solve(&self, input: &ParsedInput, part: Part) -> anyhow::Result<Answer> {
+        let Some(input) = input.downcast_ref::<S::Input>() else {
+            return Err(anyhow!("day {} part {part} was given input parsed by another solution", S::DAY));
+        };
+        S::solve(input, part)
+    }
+}
+
+pub struct Entry {
+    part: Part,
+    variant: Option<&'static str>,
+    solution: Box<dyn DynSolution>,
+}
+
+impl Entry {
+    pub fn day(&self) -> u32 {
+        self.solution.day()
+    }
+    pub fn part(&self) -> Part {
+        self.part
+    }
+    /// alternate implementations of the same part, i.e. `day2p2_optimized`
+    pub fn variant(&self) -> Option<&'static str> {
+        self.variant
+    }
+    pub fn name(&self) -> String {
+        let name = format!("day{}p{}", self.day(), self.part);
+        match self.variant {
+            Some(variant) => format!("{name}_{variant}"),
+            None => name,
+        }
+    }
+    pub fn parse(&self, input: &str) -> anyhow::Result<ParsedInput> {
+        let _span = info_span!("parse", day = self.day(), part = self.part.number(), variant = self.variant).entered();
+        self.solution.parse(input)
+    }
+    pub fn solve(&self, input: &ParsedInput) -> anyhow::Result<Answer> {
+        let _span = info_span!("solve", day = self.day(), part = self.part.number(), variant = self.variant).entered();
+        let answer = self.solution.solve(input, self.part);
+        match &answer {
+            Ok(answer) => debug!(%answer, "solved"),
+            Err(err) => debug!(%err, "failed"),
+        }
+        answer
+    }
+    pub fn run(&self, input: &str) -> anyhow::Result<Answer> {
+        self.solve(&self.parse(input)?)
+    }
+    fn key(&self) -> (u32, Part, Option<&'static str>) {
+        (self.day(), self.part, self.variant)
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {} part {}", self.day(), self.part)?;
+        if let Some(variant) = self.variant {
+            write!(f, " ({variant})")?;
+        }
+        Ok(())
+    }
+}
+
+/// all known solutions, ordered by day, part then variant
+#[derive(Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+impl Registry {
+    pub fn with(register: impl FnOnce(&mut Registry)) -> Self {
+        let mut registry = Self::default();
+        register(&mut registry);
+        registry
+    }
+    pub fn register<S>(&mut self, part: Part) -> &mut Self
+    where
+        S: Solution + 'static,
+        S::Input: 'static,
+    {
+        self.insert::<S>(part, None)
+    }
+    pub fn register_variant<S>(&mut self, part: Part, variant: &'static str) -> &mut Self
+    where
+        S: Solution + 'static,
+        S::Input: 'static,
+    {
+        self.insert::<S>(part, Some(variant))
+    }
+    fn insert<S>(&mut self, part: Part, variant: Option<&'static str>) -> &mut Self
+    where
+        S: Solution + 'static,
+        S::Input: 'static,
+    {
+        let entry = Entry {
+            part,
+            variant,
+            solution: Box::new(SolutionHandle::<S>(PhantomData)),
+        };
+        match self.entries.binary_search_by_key(&entry.key(), Entry::key) {
+            Ok(_) => panic!("{entry} is already registered"),
+            Err(ix) => self.entries.insert(ix, entry),
+        }
+        self
+    }
+    /// the primary (non variant) solution for the day and part
+    pub fn get(&self, day: u32, part: Part) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.day() == day && entry.part == part && entry.variant.is_none())
+    }
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+    pub fn days(&self) -> Vec<u32> {
+        let mut days = self.entries.iter().map(Entry::day).collect::<Vec<_>>();
+        days.dedup();
+        days
+    }
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Doubler;
+
+    impl Solution for Doubler {
+        const DAY: u32 = 2;
+        type Input = u32;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.trim().parse()?)
+        }
+
+        fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok((input * 2).into())
+        }
+    }
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const DAY: u32 = 1;
+        type Input = String;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.to_string())
+        }
+
+        fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok(input.as_str().into())
+        }
+    }
+
+    #[test]
+    fn registry_order_test() {
+        let registry = Registry::with(|registry| {
+            registry
+                .register::<Doubler>(Part::One)
+                .register::<Echo>(Part::Two)
+                .register_variant::<Doubler>(Part::One, "fast");
+        });
+        let names = registry.entries().map(Entry::name).collect::<Vec<_>>();
+        assert_eq!(names, vec!["day1p2", "day2p1", "day2p1_fast"]);
+        assert_eq!(registry.days(), vec![1, 2]);
+        assert_eq!(registry.get(2, Part::One).map(Entry::variant), Some(None));
+        assert!(registry.get(2, Part::Two).is_none());
+    }
+
+    #[test]
+    fn entry_run_test() -> anyhow::Result<()> {
+        let registry = Registry::with(|registry| {
+            registry.register::<Doubler>(Part::One).register::<Echo>(Part::Two);
+        });
+        assert_eq!(registry.get(2, Part::One).unwrap().run("21")?, Answer::from(42u32));
+        assert_eq!(registry.get(1, Part::Two).unwrap().run("hi")?, Answer::from("hi"));
+        Ok(())
+    }
+
+    #[test]
+    fn entry_solve_mismatched_input_test() -> anyhow::Result<()> {
+        let registry = Registry::with(|registry| {
+            registry.register::<Doubler>(Part::One).register::<Echo>(Part::Two);
+        });
+        let input = registry.get(1, Part::Two).unwrap().parse("hi")?;
+        assert!(registry.get(2, Part::One).unwrap().solve(&input).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn register_duplicate_test() {
+        Registry::with(|registry| {
+            registry.register::<Doubler>(Part::One).register::<Doubler>(Part::One);
+        });
+    }
+}
@@ -0,0 +1,284 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::input::InputResolver;
+use crate::registry::{Entry, Registry};
+use crate::solution::Answer;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Solved(Answer),
+    Failed(String),
+    Panicked(String),
+    /// the worker is abandoned and keeps running until the process exits
+    TimedOut,
+}
+
+impl Outcome {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Self::Solved(_))
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Solved(answer) => write!(f, "{answer}"),
+            Self::Failed(err) => write!(f, "error: {err}"),
+            Self::Panicked(message) => write!(f, "panicked: {message}"),
+            Self::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunResult {
+    /// the entry's display name, i.e. `day 2 part 2 (optimized)`
+    pub label: String,
+    pub outcome: Outcome,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunReport {
+    /// in registry order
+    pub results: Vec<RunResult>,
+    pub wall_time: Duration,
+}
+
+impl RunReport {
+    pub fn solved(&self) -> usize {
+        self.results.iter().filter(|result| result.outcome.is_solved()).count()
+    }
+    pub fn failed(&self) -> usize {
+        self.results.len() - self.solved()
+    }
+}
+
+impl Display for RunReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let label_width = self.results.iter().map(|result| result.label.len()).max().unwrap_or(0);
+        let durations = self.results.iter().map(|result| format!("{:.2?}", result.duration)).collect::<Vec<_>>();
+        let duration_width = durations.iter().map(String::len).max().unwrap_or(0);
+        for (result, duration) in self.results.iter().zip(durations) {
+            writeln!(f, "{:<label_width$}  {:>duration_width$}  {}", result.label, duration, result.outcome)?;
+        }
+        write!(
+            f,
+            "{} solved, {} failed, total wall time {:.2?}",
+            self.solved(),
+            self.failed(),
+            self.wall_time,
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    /// worker threads, defaults to the available parallelism
+    pub jobs: Option<usize>,
+    /// entries still running after this long are reported as [`Outcome::TimedOut`]
+    pub timeout: Option<Duration>,
+}
+
+enum Message {
+    Started(usize, Instant),
+    Finished(usize, Outcome, Duration),
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+fn run_entry(entry: &Entry, resolver: &InputResolver) -> Outcome {
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        resolver
+            .load(entry.day(), None)
+            .map_err(anyhow::Error::from)
+            .and_then(|input| entry.run(&input))
+    }));
+    match result {
+        Ok(Ok(answer)) => Outcome::Solved(answer),
+        Ok(Err(err)) => Outcome::Failed(format!("{err:#}")),
+        Err(payload) => Outcome::Panicked(panic_message(payload)),
+    }
+}
+
+fn spawn_worker(
+    registry: Arc<Registry>,
+    resolver: InputResolver,
+    next: Arc<AtomicUsize>,
+    sender: mpsc::Sender<Message>,
+) {
+    thread::spawn(move || loop {
+        let ix = next.fetch_add(1, Ordering::SeqCst);
+        let Some(entry) = registry.entries().nth(ix) else {
+            break;
+        };
+        let start = Instant::now();
+        if sender.send(Message::Started(ix, start)).is_err() {
+            break;
+        }
+        let outcome = run_entry(entry, &resolver);
+        if sender.send(Message::Finished(ix, outcome, start.elapsed())).is_err() {
+            break;
+        }
+    });
+}
+
+/// runs every entry against its input on a pool of worker threads,
+/// a panic or timeout only affects the entry that caused it
+pub fn run_all(registry: Arc<Registry>, resolver: InputResolver, options: RunOptions) -> RunReport {
+    let start = Instant::now();
+    let total = registry.len();
+    let jobs = options
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from))
+        .clamp(1, total.max(1));
+    let labels = registry.entries().map(Entry::to_string).collect::<Vec<_>>();
+    let next = Arc::new(AtomicUsize::new(0));
+    let (sender, receiver) = mpsc::channel();
+    for _ in 0..jobs {
+        spawn_worker(registry.clone(), resolver.clone(), next.clone(), sender.clone());
+    }
+
+    let mut outcomes: Vec<Option<(Outcome, Duration)>> = vec![None; total];
+    let mut running: HashMap<usize, Instant> = HashMap::new();
+    let mut done = 0;
+    while done < total {
+        let message = match options.timeout.and_then(|timeout| running.values().min().map(|started| *started + timeout)) {
+            Some(deadline) => receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match message {
+            Ok(Message::Started(ix, started)) => {
+                running.insert(ix, started);
+            }
+            Ok(Message::Finished(ix, outcome, duration)) => {
+                if running.remove(&ix).is_some() {
+                    outcomes[ix] = Some((outcome, duration));
+                    done += 1;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let timeout = options.timeout.unwrap_or_default();
+                let timed_out = running
+                    .iter()
+                    .filter(|(_, started)| started.elapsed() >= timeout)
+                    .map(|(ix, _)| *ix)
+                    .collect::<Vec<_>>();
+                for ix in timed_out {
+                    running.remove(&ix);
+                    outcomes[ix] = Some((Outcome::TimedOut, timeout));
+                    done += 1;
+                    // the stuck worker can't be stopped, replace it so the rest still run
+                    spawn_worker(registry.clone(), resolver.clone(), next.clone(), sender.clone());
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    let results = labels
+        .into_iter()
+        .zip(outcomes)
+        .map(|(label, outcome)| {
+            let (outcome, duration) = outcome.unwrap_or((Outcome::Failed("never ran".to_string()), Duration::ZERO));
+            RunResult { label, outcome, duration }
+        })
+        .collect();
+    RunReport {
+        results,
+        wall_time: start.elapsed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::solution::{Part, Solution};
+    use super::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u32 = 1;
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok(input.len().into())
+        }
+
+        fn part2(_input: &Self::Input) -> anyhow::Result<Answer> {
+            panic!("not yet")
+        }
+    }
+
+    struct Slow;
+
+    impl Solution for Slow {
+        const DAY: u32 = 2;
+        type Input = ();
+
+        fn parse(_input: &str) -> anyhow::Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> anyhow::Result<Answer> {
+            thread::sleep(Duration::from_secs(5));
+            Ok(0.into())
+        }
+    }
+
+    #[test]
+    fn run_all_test() -> anyhow::Result<()> {
+        let root = std::env::temp_dir().join(format!("advent_of_code_runner_{}", std::process::id()));
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("day1"), "a\nb")?;
+        fs::write(root.join("day2"), "slow")?;
+        let registry = Registry::with(|registry| {
+            registry
+                .register::<Lines>(Part::One)
+                .register::<Lines>(Part::Two)
+                .register::<Slow>(Part::One)
+                .register::<Slow>(Part::Two)
+                .register_variant::<Lines>(Part::One, "again");
+        });
+        let report = run_all(Arc::new(registry), InputResolver::new(&root), RunOptions {
+            jobs: Some(1),
+            timeout: Some(Duration::from_millis(200)),
+        });
+        let outcomes = report
+            .results
+            .iter()
+            .map(|result| (result.label.as_str(), result.outcome.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(outcomes, vec![
+            ("day 1 part 1", Outcome::Solved(2.into())),
+            ("day 1 part 1 (again)", Outcome::Solved(2.into())),
+            ("day 1 part 2", Outcome::Panicked("not yet".to_string())),
+            ("day 2 part 1", Outcome::TimedOut),
+            ("day 2 part 2", Outcome::Failed("day 2 part 2 is not solved".to_string())),
+        ]);
+        assert_eq!((report.solved(), report.failed()), (2, 3));
+        fs::remove_dir_all(root)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{bail, Context};
+use crate::solution::Part;
+
+const DAY_TEMPLATE: &str = include_str!("templates/day.rs.template");
+
+/// default location of the day binaries
+pub fn bin_dir() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/src/bin"))
+}
+
+pub fn render_day(day: u32, part: Part) -> String {
+    DAY_TEMPLATE
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{PART}}", &part.number().to_string())
+        .replace("{{PART_NAME}}", &format!("{part:?}"))
+}
+
+fn module_day(line: &str, prefix: &str) -> Option<u32> {
+    let digits = line.trim().strip_prefix(prefix)?;
+    let digits = &digits[..digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len())];
+    digits.parse().ok()
+}
+
+/// adds the `#[path]` module and its `register` call to the runner's `solutions.rs`, keeping day order
+pub fn add_to_runner(solutions: &str, module: &str, day: u32) -> anyhow::Result<String> {
+    let lines = solutions.lines().collect::<Vec<_>>();
+    if lines.iter().any(|line| line.trim() == format!("mod {module};")) {
+        bail!("{module} is already part of the runner");
+    }
+    let Some(first_mod) = lines.iter().position(|line| line.starts_with("#[path")) else {
+        bail!("no modules found in solutions.rs");
+    };
+    let mod_ix = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| module_day(line, "mod day").is_some_and(|other| other <= day))
+        .map(|(ix, _)| ix + 1)
+        .next_back()
+        .unwrap_or(first_mod);
+    let Some(register_all) = lines.iter().position(|line| line.contains("fn register_all")) else {
+        bail!("no register_all found in solutions.rs");
+    };
+    let register_ix = lines
+        .iter()
+        .enumerate()
+        .skip(register_all)
+        .take_while(|(_, line)| **line != "}")
+        .filter(|(_, line)| module_day(line, "day").is_some_and(|other| other <= day))
+        .map(|(ix, _)| ix + 1)
+        .last()
+        .unwrap_or(register_all + 1);
+
+    let path_line = format!("#[path = \"../{module}.rs\"]");
+    let mod_line = format!("mod {module};");
+    let register_line = format!("    {module}::register(registry);");
+    let mut result = vec![];
+    for (ix, line) in lines.into_iter().enumerate() {
+        if ix == mod_ix {
+            result.push(path_line.as_str());
+            result.push(mod_line.as_str());
+        }
+        if ix == register_ix {
+            result.push(register_line.as_str());
+        }
+        result.push(line);
+    }
+    Ok(result.join("\n") + "\n")
+}
+
+/// writes `<bin_dir>/dayNp1.rs` and `<bin_dir>/dayNp2.rs` from the template and wires them into `<bin_dir>/aoc/solutions.rs`
+pub fn scaffold_day(bin_dir: impl AsRef<Path>, day: u32) -> anyhow::Result<Vec<PathBuf>> {
+    let bin_dir = bin_dir.as_ref();
+    let solutions_path = bin_dir.join("aoc").join("solutions.rs");
+    let mut solutions = fs::read_to_string(&solutions_path).with_context(|| format!("failed to read {solutions_path:?}"))?;
+    let mut files = vec![];
+    for part in [Part::One, Part::Two] {
+        let module = format!("day{day}p{part}");
+        let path = bin_dir.join(format!("{module}.rs"));
+        if path.exists() {
+            bail!("{path:?} already exists");
+        }
+        solutions = add_to_runner(&solutions, &module, day)?;
+        files.push((path, render_day(day, part)));
+    }
+    for (path, contents) in &files {
+        fs::write(path, contents).with_context(|| format!("failed to write {path:?}"))?;
+    }
+    fs::write(&solutions_path, solutions).with_context(|| format!("failed to write {solutions_path:?}"))?;
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLUTIONS: &str = "\
+use advent_of_code::registry::Registry;
+
+#[path = \"../day1p1.rs\"]
+mod day1p1;
+#[path = \"../day19p2.rs\"]
+mod day19p2;
+
+pub fn register_all(registry: &mut Registry) {
+    day1p1::register(registry);
+    day19p2::register(registry);
+}
+";
+
+    #[test]
+    fn render_day_test() {
+        let rendered = render_day(20, Part::Two);
+        assert!(rendered.contains("struct Day20;"));
+        assert!(rendered.contains("const DAY: u32 = 20;"));
+        assert!(rendered.contains("register::<Day20>(Part::Two)"));
+        assert!(rendered.contains("Day20::part2(&input)?"));
+        assert!(!rendered.contains("fn part2"));
+        assert!(!rendered.contains("{{"));
+    }
+
+    #[test]
+    fn add_to_runner_test() -> anyhow::Result<()> {
+        let expected = "\
+use advent_of_code::registry::Registry;
+
+#[path = \"../day1p1.rs\"]
+mod day1p1;
+#[path = \"../day14.rs\"]
+mod day14;
+#[path = \"../day19p2.rs\"]
+mod day19p2;
+
+pub fn register_all(registry: &mut Registry) {
+    day1p1::register(registry);
+    day14::register(registry);
+    day19p2::register(registry);
+}
+";
+        assert_eq!(add_to_runner(SOLUTIONS, "day14", 14)?, expected);
+        assert!(add_to_runner(expected, "day14", 14).is_err());
+
+        let appended = add_to_runner(SOLUTIONS, "day20", 20)?;
+        assert!(appended.contains("mod day19p2;\n#[path = \"../day20.rs\"]\nmod day20;\n"));
+        assert!(appended.contains("day19p2::register(registry);\n    day20::register(registry);\n}"));
+
+        let with_attempts = format!("{SOLUTIONS}\npub fn register_attempts(registry: &mut Registry) {{\n    day4p1a::register(registry);\n}}\n");
+        let appended = add_to_runner(&with_attempts, "day20", 20)?;
+        assert!(appended.contains("day19p2::register(registry);\n    day20::register(registry);\n}"));
+        assert!(appended.ends_with("day4p1a::register(registry);\n}\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn scaffold_day_test() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-test-{}", std::process::id()));
+        fs::create_dir_all(dir.join("aoc"))?;
+        fs::write(dir.join("aoc").join("solutions.rs"), SOLUTIONS)?;
+        let paths = scaffold_day(&dir, 20)?;
+        assert_eq!(paths, vec![dir.join("day20p1.rs"), dir.join("day20p2.rs")]);
+        assert!(fs::read_to_string(&paths[0])?.contains("Day20::part1(&input)?"));
+        let solutions = fs::read_to_string(dir.join("aoc").join("solutions.rs"))?;
+        assert!(solutions.contains("mod day20p1;\n#[path = \"../day20p2.rs\"]\nmod day20p2;\n"));
+        assert!(scaffold_day(&dir, 20).is_err());
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use anyhow::bail;
+use thiserror::Error;
+use crate::logging::init_tracing;
+use crate::read_input;
+use crate::registry::Registry;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("invalid part: {0:?}, expected 1 or 2")]
+pub struct InvalidPart(String);
+
+impl FromStr for Part {
+    type Err = InvalidPart;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(InvalidPart(other.to_string())),
+        }
+    }
+}
+
+/// the displayable result of solving a part
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Self(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String, &str);
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SolutionError {
+    #[error("day {day} part {part} is not solved")]
+    Unsolved { day: u32, part: Part },
+}
+
+pub trait Solution {
+    const DAY: u32;
+    type Input;
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+    fn part1(_input: &Self::Input) -> anyhow::Result<Answer> {
+        Err(SolutionError::Unsolved { day: Self::DAY, part: Part::One }.into())
+    }
+    fn part2(_input: &Self::Input) -> anyhow::Result<Answer> {
+        Err(SolutionError::Unsolved { day: Self::DAY, part: Part::Two }.into())
+    }
+    fn solve(input: &Self::Input, part: Part) -> anyhow::Result<Answer> {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+}
+
+/// entry point for the standalone `dayNpM` binaries,
+/// runs everything `register` adds against the default input for its day,
+/// a failing entry is reported and the rest still run
+pub fn run_main(register: fn(&mut Registry)) -> anyhow::Result<()> {
+    init_tracing();
+    let registry = Registry::with(register);
+    let mut failed = 0;
+    for entry in registry.entries() {
+        let answer = read_input(entry.day())
+            .map_err(anyhow::Error::from)
+            .and_then(|input| entry.run(&input));
+        match answer {
+            Ok(answer) => println!("{entry}: {answer}"),
+            Err(err) => {
+                failed += 1;
+                eprintln!("{entry}: {err:#}");
+            }
+        }
+    }
+    if failed > 0 {
+        bail!("{failed} of {} failed", registry.entries().count());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        const DAY: u32 = 0;
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.split_whitespace().map(|item| item.parse()).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+    }
+
+    #[test]
+    fn part_from_str_test() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn solve_test() -> anyhow::Result<()> {
+        let input = Example::parse("1 2 3")?;
+        assert_eq!(Example::solve(&input, Part::One)?, Answer::from(6u32));
+        let err = Example::solve(&input, Part::Two).unwrap_err();
+        assert_eq!(err.downcast_ref(), Some(&SolutionError::Unsolved { day: 0, part: Part::Two }));
+        Ok(())
+    }
+}
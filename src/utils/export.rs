@@ -0,0 +1,363 @@
+use std::io::Write;
+use std::path::Path;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use glam::{IVec2, UVec2};
+use thiserror::Error;
+use crate::utils::matrix::MatrixView;
+
+#[derive(Error, Debug)]
+pub enum ExportError {
+    #[error("unsupported image format {0:?}, expected ppm, png or svg")]
+    UnsupportedFormat(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 40, 40);
+    pub const GREEN: Rgb = Rgb(40, 180, 60);
+    pub const BLUE: Rgb = Rgb(40, 90, 220);
+    pub const YELLOW: Rgb = Rgb(240, 200, 40);
+
+    /// `#rrggbb`
+    pub fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+    /// evenly spread, stable colour for a label i.e. a region index
+    pub fn from_label(label: usize) -> Rgb {
+        // golden angle steps keep neighboring labels apart
+        let hue = (label as f64 * 137.508) % 360.0;
+        let sector = hue / 60.0;
+        let x = 1.0 - (sector % 2.0 - 1.0).abs();
+        let (r, g, b) = match sector as u32 {
+            0 => (1.0, x, 0.0),
+            1 => (x, 1.0, 0.0),
+            2 => (0.0, 1.0, x),
+            3 => (0.0, x, 1.0),
+            4 => (x, 0.0, 1.0),
+            _ => (1.0, 0.0, x),
+        };
+        let channel = |value: f64| (60.0 + value * 170.0) as u8;
+        Rgb(channel(r), channel(g), channel(b))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    /// binary `P6` portable pixmap
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    /// picks the format from the file extension
+    pub fn from_path(path: &Path) -> Result<Self, ExportError> {
+        let extension = path.extension().and_then(|extension| extension.to_str()).unwrap_or_default();
+        match extension.to_ascii_lowercase().as_str() {
+            "ppm" => Ok(ImageFormat::Ppm),
+            "png" => Ok(ImageFormat::Png),
+            "svg" => Ok(ImageFormat::Svg),
+            _ => Err(ExportError::UnsupportedFormat(extension.to_string())),
+        }
+    }
+}
+
+type ColorFn<'a, T> = Box<dyn Fn(&T) -> Rgb + 'a>;
+
+/// image of a view where every cell is a `cell_size` square,
+/// with an optional path drawn as a line through the centers of its cells
+///
+/// example:
+///     GridImage::new(&grid, |&wall| if wall { Rgb::BLACK } else { Rgb::WHITE })
+///         .cell_size(8)
+///         .path(path, Rgb::RED)
+///         .write("day18.png")?;
+pub struct GridImage<'a, V: MatrixView> {
+    view: &'a V,
+    color: ColorFn<'a, V::Item>,
+    cell_size: u32,
+    /// colour of cells the view doesn't map, i.e. the gaps of a skew
+    background: Rgb,
+    path: Vec<UVec2>,
+    path_color: Rgb,
+}
+
+impl<'a, V: MatrixView> GridImage<'a, V> {
+    pub fn new(view: &'a V, color: impl Fn(&V::Item) -> Rgb + 'a) -> Self {
+        Self {
+            view,
+            color: Box::new(color),
+            cell_size: 4,
+            background: Rgb::WHITE,
+            path: vec![],
+            path_color: Rgb::RED,
+        }
+    }
+    /// pixels per cell side, at least 1
+    pub fn cell_size(mut self, cell_size: u32) -> Self {
+        self.cell_size = cell_size.max(1);
+        self
+    }
+    pub fn background(mut self, background: Rgb) -> Self {
+        self.background = background;
+        self
+    }
+    pub fn path(mut self, path: impl IntoIterator<Item = UVec2>, color: Rgb) -> Self {
+        self.path = path.into_iter().collect();
+        self.path_color = color;
+        self
+    }
+    /// image size in pixels
+    pub fn size(&self) -> UVec2 {
+        self.view.size() * self.cell_size
+    }
+    fn cell_color(&self, pos: UVec2) -> Rgb {
+        self.view.cell(pos).map_or(self.background, |cell| (self.color)(cell))
+    }
+    fn stroke_width(&self) -> u32 {
+        (self.cell_size / 4).max(1)
+    }
+    /// row major pixels with the path drawn on top
+    pub fn pixels(&self) -> Vec<Rgb> {
+        let size = self.size();
+        let mut pixels = Vec::with_capacity((size.x * size.y) as usize);
+        for y in 0..size.y {
+            for x in 0..size.x {
+                pixels.push(self.cell_color(UVec2::new(x, y) / self.cell_size));
+            }
+        }
+        let center = |pos: UVec2| (pos * self.cell_size + self.cell_size / 2).as_ivec2();
+        let stroke = self.stroke_width() as i32;
+        let mut plot = |point: IVec2| {
+            let min = point - IVec2::splat(stroke / 2);
+            for y in min.y..min.y + stroke {
+                for x in min.x..min.x + stroke {
+                    if x >= 0 && y >= 0 && (x as u32) < size.x && (y as u32) < size.y {
+                        pixels[(y as u32 * size.x + x as u32) as usize] = self.path_color;
+                    }
+                }
+            }
+        };
+        match self.path.as_slice() {
+            [] => {}
+            [only] => plot(center(*only)),
+            path => {
+                for step in path.windows(2) {
+                    for point in line(center(step[0]), center(step[1])) {
+                        plot(point);
+                    }
+                }
+            }
+        }
+        pixels
+    }
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let size = self.size();
+        let mut bytes = format!("P6\n{} {}\n255\n", size.x, size.y).into_bytes();
+        for Rgb(r, g, b) in self.pixels() {
+            bytes.extend([r, g, b]);
+        }
+        bytes
+    }
+    /// 8 bit RGB png, deflate compressed
+    pub fn to_png(&self) -> Vec<u8> {
+        let size = self.size();
+        let pixels = self.pixels();
+        let mut raw = Vec::with_capacity(pixels.len() * 3 + size.y as usize);
+        for row in pixels.chunks(size.x.max(1) as usize) {
+            // filter type none
+            raw.push(0);
+            for Rgb(r, g, b) in row {
+                raw.extend([*r, *g, *b]);
+            }
+        }
+        let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+        encoder.write_all(&raw).expect("writing to a vec can't fail");
+        let data = encoder.finish().expect("writing to a vec can't fail");
+
+        let mut header = vec![];
+        header.extend(size.x.to_be_bytes());
+        header.extend(size.y.to_be_bytes());
+        // bit depth 8, colour type rgb, default compression, filtering and no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut bytes = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut bytes, b"IHDR", &header);
+        png_chunk(&mut bytes, b"IDAT", &data);
+        png_chunk(&mut bytes, b"IEND", &[]);
+        bytes
+    }
+    /// one rect per run of equally coloured cells in a row, the path is a polyline
+    pub fn to_svg(&self) -> String {
+        let size = self.size();
+        let cells = self.view.size();
+        let cell = self.cell_size;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+            size.x, size.y, size.x, size.y,
+        );
+        for y in 0..cells.y {
+            let mut x = 0;
+            while x < cells.x {
+                let color = self.cell_color(UVec2::new(x, y));
+                let run = (x..cells.x).take_while(|&end| self.cell_color(UVec2::new(end, y)) == color).count() as u32;
+                svg += &format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+                    x * cell, y * cell, run * cell, cell, color.hex(),
+                );
+                x += run;
+            }
+        }
+        if !self.path.is_empty() {
+            let points = self.path
+                .iter()
+                .map(|pos| {
+                    let center = pos * cell + cell / 2;
+                    format!("{},{}", center.x, center.y)
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            svg += &format!(
+                "<polyline points=\"{points}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" stroke-linecap=\"square\" stroke-linejoin=\"round\"/>\n",
+                self.path_color.hex(),
+                self.stroke_width(),
+            );
+        }
+        svg += "</svg>\n";
+        svg
+    }
+    pub fn encode(&self, format: ImageFormat) -> Vec<u8> {
+        match format {
+            ImageFormat::Ppm => self.to_ppm(),
+            ImageFormat::Png => self.to_png(),
+            ImageFormat::Svg => self.to_svg().into_bytes(),
+        }
+    }
+    /// writes the image in the format matching the file extension
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), ExportError> {
+        let path = path.as_ref();
+        let format = ImageFormat::from_path(path)?;
+        std::fs::write(path, self.encode(format))?;
+        Ok(())
+    }
+}
+
+fn png_chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend((data.len() as u32).to_be_bytes());
+    let start = bytes.len();
+    bytes.extend(kind);
+    bytes.extend(data);
+    let crc = crc32fast::hash(&bytes[start..]);
+    bytes.extend(crc.to_be_bytes());
+}
+
+/// points of a straight line including both ends (bresenham)
+fn line(from: IVec2, to: IVec2) -> Vec<IVec2> {
+    let delta = (to - from).abs();
+    let step = (to - from).signum();
+    let mut error = delta.x - delta.y;
+    let mut pos = from;
+    let mut points = vec![pos];
+    while pos != to {
+        let doubled = error * 2;
+        if doubled > -delta.y {
+            error -= delta.y;
+            pos.x += step.x;
+        }
+        if doubled < delta.x {
+            error += delta.x;
+            pos.y += step.y;
+        }
+        points.push(pos);
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use flate2::read::ZlibDecoder;
+    use crate::utils::matrix::Grid;
+    use super::*;
+
+    fn image(grid: &Grid<bool>) -> GridImage<'_, Grid<bool>> {
+        GridImage::new(grid, |&wall| if wall { Rgb::BLACK } else { Rgb::WHITE })
+    }
+
+    #[test]
+    fn ppm_test() {
+        let grid = Grid::from_matrix(&[vec![true, false]]).unwrap();
+        let ppm = image(&grid).cell_size(1).to_ppm();
+        assert_eq!(ppm, b"P6\n2 1\n255\n\x00\x00\x00\xff\xff\xff".to_vec());
+
+        let ppm = image(&grid).cell_size(2).to_ppm();
+        assert!(ppm.starts_with(b"P6\n4 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 2 * 3);
+    }
+
+    #[test]
+    fn path_test() {
+        let grid = Grid::filled(3, 3, false);
+        let path = [UVec2::new(0, 0), UVec2::new(2, 0), UVec2::new(2, 2)];
+        let pixels = image(&grid).cell_size(1).path(path, Rgb::RED).pixels();
+        let red = pixels.iter().map(|&pixel| pixel == Rgb::RED).collect::<Vec<_>>();
+        assert_eq!(red, vec![true, true, true, false, false, true, false, false, true]);
+        assert_eq!(line(IVec2::new(0, 0), IVec2::new(3, -1)).last(), Some(&IVec2::new(3, -1)));
+    }
+
+    #[test]
+    fn png_test() {
+        let grid = Grid::from_matrix(&[vec![true, false], vec![false, true]]).unwrap();
+        let png = image(&grid).cell_size(1).to_png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 2, 0, 0, 0, 2]);
+        // the crc of an empty IEND chunk is always the same
+        assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(&png[37..41], b"IDAT");
+        let mut raw = vec![];
+        ZlibDecoder::new(&png[41..41 + idat_len]).read_to_end(&mut raw).unwrap();
+        assert_eq!(raw, vec![0, 0, 0, 0, 255, 255, 255, 0, 255, 255, 255, 0, 0, 0]);
+    }
+
+    #[test]
+    fn svg_test() {
+        let grid = Grid::from_matrix(&[vec![true, true, false]]).unwrap();
+        let svg = image(&grid).cell_size(2).path([UVec2::new(0, 0), UVec2::new(2, 0)], Rgb::BLUE).to_svg();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"6\" height=\"2\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"4\" height=\"2\" fill=\"#000000\"/>"));
+        assert!(svg.contains("<rect x=\"4\" y=\"0\" width=\"2\" height=\"2\" fill=\"#ffffff\"/>"));
+        assert!(svg.contains("<polyline points=\"1,1 5,1\" fill=\"none\" stroke=\"#285adc\""));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn write_test() -> Result<(), ExportError> {
+        let grid = Grid::filled(2, 2, true);
+        let dir = std::env::temp_dir().join(format!("aoc-export-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        for (name, magic) in [("grid.ppm", b"P6".as_slice()), ("grid.PNG", b"\x89PNG"), ("grid.svg", b"<svg")] {
+            image(&grid).write(dir.join(name))?;
+            assert!(std::fs::read(dir.join(name))?.starts_with(magic), "{name}");
+        }
+        assert!(matches!(image(&grid).write(dir.join("grid.gif")), Err(ExportError::UnsupportedFormat(format)) if format == "gif"));
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn from_label_test() {
+        assert_ne!(Rgb::from_label(0), Rgb::from_label(1));
+        assert_eq!(Rgb::from_label(7), Rgb::from_label(7));
+    }
+}
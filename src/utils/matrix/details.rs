@@ -0,0 +1,195 @@
+use glam::{IVec2, UVec2};
+
+/// rectangular bounds of a matrix
+///
+/// `min` is inclusive and `end` is exclusive, so a `width`x`height` matrix at the origin
+/// spans `(0, 0)..(width, height)` and holds `width * height` cells.
+/// positions are signed so bounds can sit anywhere, i.e. a sparse map that grew into negative coordinates.
+///
+/// indexes are row major and relative to `min`, [`MatrixDetails::index_from_pos`] and
+/// [`MatrixDetails::pos_from_index`] round-trip for every position within bounds.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MatrixDetails {
+    min: IVec2,
+    end: IVec2,
+}
+
+impl MatrixDetails {
+    /// `min` inclusive, `end` exclusive, an `end` below `min` on either axis gives empty bounds
+    pub fn new(min: IVec2, end: IVec2) -> Self {
+        Self {
+            min,
+            end: end.max(min),
+        }
+    }
+    /// `width`x`height` bounds starting at `origin`
+    pub fn from_size(origin: IVec2, size: UVec2) -> Self {
+        Self::new(origin, origin + size.as_ivec2())
+    }
+    /// bounds covering `min` through `max`, both inclusive
+    pub fn from_inclusive(min: IVec2, max: IVec2) -> Self {
+        if max.x < min.x || max.y < min.y {
+            return Self::new(min, min);
+        }
+        Self::new(min, max + IVec2::ONE)
+    }
+    /// bounds at the origin sized by the matrix, the widest row is used for ragged matrices
+    pub fn from_matrix<T>(matrix: &[Vec<T>]) -> Self {
+        let width = matrix.iter().map(Vec::len).max().unwrap_or(0);
+        Self::from_size(IVec2::ZERO, UVec2::new(width as u32, matrix.len() as u32))
+    }
+    /// smallest bounds containing every position, empty when there are none
+    pub fn from_positions(positions: impl IntoIterator<Item = IVec2>) -> Self {
+        let mut positions = positions.into_iter();
+        let Some(first) = positions.next() else {
+            return Self::default();
+        };
+        let (min, max) = positions.fold((first, first), |(min, max), pos| (min.min(pos), max.max(pos)));
+        Self::from_inclusive(min, max)
+    }
+    /// first position, inclusive
+    pub fn min(&self) -> IVec2 {
+        self.min
+    }
+    /// one past the last position on each axis, exclusive
+    pub fn end(&self) -> IVec2 {
+        self.end
+    }
+    /// last position, inclusive, `None` when empty
+    pub fn max_inclusive(&self) -> Option<IVec2> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.end - IVec2::ONE)
+    }
+    pub fn width(&self) -> usize {
+        (self.end.x - self.min.x) as usize
+    }
+    pub fn height(&self) -> usize {
+        (self.end.y - self.min.y) as usize
+    }
+    pub fn size(&self) -> UVec2 {
+        UVec2::new(self.width() as u32, self.height() as u32)
+    }
+    /// number of positions within bounds, valid indexes are `0..len()`
+    pub fn len(&self) -> usize {
+        self.width() * self.height()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn contains(&self, pos: IVec2) -> bool {
+        pos.cmpge(self.min).all() && pos.cmplt(self.end).all()
+    }
+    pub fn is_within_bounds(&self, pos: UVec2) -> bool {
+        pos.x <= i32::MAX as u32 && pos.y <= i32::MAX as u32 && self.contains(pos.as_ivec2())
+    }
+    pub fn index_from_pos(&self, pos: IVec2) -> Option<usize> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let offset = pos - self.min;
+        Some(offset.y as usize * self.width() + offset.x as usize)
+    }
+    pub fn pos_from_index(&self, index: usize) -> Option<IVec2> {
+        if index >= self.len() {
+            return None;
+        }
+        let x = (index % self.width()) as i32;
+        let y = (index / self.width()) as i32;
+        Some(self.min + IVec2::new(x, y))
+    }
+    /// every position within bounds in index order
+    pub fn positions(&self) -> impl DoubleEndedIterator<Item = IVec2> + ExactSizeIterator {
+        let details = *self;
+        (0..self.len()).map(move |ix| details.pos_from_index(ix).expect("index within bounds"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    #[test]
+    fn from_matrix_test() {
+        let matrix = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let details = MatrixDetails::from_matrix(&matrix);
+        assert_eq!((details.width(), details.height(), details.len()), (3, 2, 6));
+        assert_eq!(details.min(), IVec2::ZERO);
+        assert_eq!(details.end(), IVec2::new(3, 2));
+        assert_eq!(details.max_inclusive(), Some(IVec2::new(2, 1)));
+        assert!(details.is_within_bounds(UVec2::new(2, 1)));
+        assert!(!details.is_within_bounds(UVec2::new(3, 1)));
+        assert!(!details.is_within_bounds(UVec2::new(0, 2)));
+        assert!(!details.is_within_bounds(UVec2::MAX));
+
+        let empty = MatrixDetails::from_matrix::<i32>(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.max_inclusive(), None);
+        assert!(!empty.contains(IVec2::ZERO));
+    }
+
+    #[test]
+    fn origin_test() {
+        let details = MatrixDetails::from_inclusive(IVec2::new(-2, 3), IVec2::new(1, 4));
+        assert_eq!(details.size(), UVec2::new(4, 2));
+        assert_eq!(details.index_from_pos(IVec2::new(-2, 3)), Some(0));
+        assert_eq!(details.index_from_pos(IVec2::new(1, 4)), Some(7));
+        assert_eq!(details.pos_from_index(5), Some(IVec2::new(-1, 4)));
+        assert_eq!(details.pos_from_index(8), None);
+        assert_eq!(details.index_from_pos(IVec2::new(0, 2)), None);
+        assert_eq!(details.positions().next_back(), Some(IVec2::new(1, 4)));
+
+        assert!(MatrixDetails::from_inclusive(IVec2::ONE, IVec2::ZERO).is_empty());
+        assert!(MatrixDetails::new(IVec2::ONE, IVec2::ZERO).is_empty());
+        assert_eq!(
+            MatrixDetails::from_positions([IVec2::new(3, -1), IVec2::new(-1, 2), IVec2::new(0, 0)]),
+            MatrixDetails::new(IVec2::new(-1, -1), IVec2::new(4, 3)),
+        );
+    }
+
+    fn details() -> impl Strategy<Value = MatrixDetails> {
+        (-50i32..50, -50i32..50, 0u32..20, 0u32..20)
+            .prop_map(|(x, y, width, height)| MatrixDetails::from_size(IVec2::new(x, y), UVec2::new(width, height)))
+    }
+
+    proptest! {
+        #[test]
+        fn index_round_trip(details in details(), index in 0usize..500) {
+            match details.pos_from_index(index) {
+                Some(pos) => {
+                    prop_assert!(index < details.len());
+                    prop_assert!(details.contains(pos));
+                    prop_assert_eq!(details.index_from_pos(pos), Some(index));
+                }
+                None => prop_assert!(index >= details.len()),
+            }
+        }
+
+        #[test]
+        fn pos_round_trip(details in details(), x in -80i32..80, y in -80i32..80) {
+            let pos = IVec2::new(x, y);
+            match details.index_from_pos(pos) {
+                Some(index) => {
+                    prop_assert!(index < details.len());
+                    prop_assert_eq!(details.pos_from_index(index), Some(pos));
+                }
+                None => prop_assert!(!details.contains(pos)),
+            }
+        }
+
+        #[test]
+        fn bounds_are_consistent(details in details()) {
+            prop_assert_eq!(details.positions().len(), details.len());
+            prop_assert_eq!(details.positions().all(|pos| details.contains(pos)), true);
+            if let Some(max) = details.max_inclusive() {
+                prop_assert_eq!(MatrixDetails::from_inclusive(details.min(), max), details);
+                prop_assert!(!details.contains(max + IVec2::X));
+                prop_assert!(!details.contains(max + IVec2::Y));
+                prop_assert!(!details.contains(details.min() - IVec2::X));
+                prop_assert!(!details.contains(details.min() - IVec2::Y));
+            }
+        }
+    }
+}
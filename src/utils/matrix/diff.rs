@@ -0,0 +1,177 @@
+use std::fmt::{Display, Formatter};
+use glam::UVec2;
+use crate::utils::matrix::{Glyph, MatrixView};
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum DiffLayout {
+    /// left grid, then the right grid on the same lines, changed cells red on the left and green on the right
+    #[default]
+    SideBySide,
+    /// a single grid, changed cells show the right glyph in green or, past the end of the right grid, the left glyph in red
+    Merged,
+}
+
+/// coloured cell by cell comparison of two views, the counterpart of [`super::matrix_diff`] for printing
+///
+/// views of different sizes are compared over the larger of the two, missing cells are blank and always count as changed
+///
+/// example:
+///     left:  AB    right: AX
+///            CD           CD
+///     GridDiff::new(&left, &right) with side by side layout
+///     result: AB | AX   (B in red, X in green)
+///             CD | CD
+pub struct GridDiff<'a, L, R> {
+    left: &'a L,
+    right: &'a R,
+    layout: DiffLayout,
+}
+
+impl<'a, T, L, R> GridDiff<'a, L, R>
+where
+    T: PartialEq + Glyph,
+    L: MatrixView<Item = T>,
+    R: MatrixView<Item = T>,
+{
+    pub fn new(left: &'a L, right: &'a R) -> Self {
+        Self { left, right, layout: DiffLayout::default() }
+    }
+    pub fn layout(mut self, layout: DiffLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+    /// size covering both views
+    pub fn size(&self) -> UVec2 {
+        self.left.size().max(self.right.size())
+    }
+    pub fn is_changed(&self, pos: UVec2) -> bool {
+        self.left.cell(pos) != self.right.cell(pos)
+    }
+    /// changed positions in row major order
+    pub fn changed(&self) -> Vec<UVec2> {
+        let size = self.size();
+        (0..size.y)
+            .flat_map(|y| (0..size.x).map(move |x| UVec2::new(x, y)))
+            .filter(|&pos| self.is_changed(pos))
+            .collect()
+    }
+    /// true when both views have the same size and cells
+    pub fn is_same(&self) -> bool {
+        self.left.size() == self.right.size() && self.changed().is_empty()
+    }
+    fn write_row(
+        &self,
+        f: &mut Formatter<'_>,
+        y: u32,
+        view: &impl MatrixView<Item = T>,
+        color: &str,
+    ) -> std::fmt::Result {
+        for x in 0..self.size().x {
+            let pos = UVec2::new(x, y);
+            let glyph = view.cell(pos).map_or(' ', Glyph::glyph);
+            if self.is_changed(pos) {
+                write!(f, "{color}{glyph}{RESET}")?;
+            } else {
+                write!(f, "{glyph}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, L, R> Display for GridDiff<'_, L, R>
+where
+    T: PartialEq + Glyph,
+    L: MatrixView<Item = T>,
+    R: MatrixView<Item = T>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.size().y {
+            match self.layout {
+                DiffLayout::SideBySide => {
+                    self.write_row(f, y, self.left, RED)?;
+                    write!(f, " | ")?;
+                    self.write_row(f, y, self.right, GREEN)?;
+                }
+                DiffLayout::Merged => {
+                    for x in 0..self.size().x {
+                        let pos = UVec2::new(x, y);
+                        match (self.is_changed(pos), self.right.cell(pos)) {
+                            (false, cell) => write!(f, "{}", cell.map_or(' ', Glyph::glyph))?,
+                            (true, Some(cell)) => write!(f, "{GREEN}{}{RESET}", cell.glyph())?,
+                            (true, None) => write!(f, "{RED}{}{RESET}", self.left.cell(pos).map_or(' ', Glyph::glyph))?,
+                        }
+                    }
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// like `assert_eq!` for matrices, but a mismatch panics with a side by side [`GridDiff`] instead of two debug dumps
+#[track_caller]
+pub fn assert_matrix_eq<T, L, R>(left: &L, right: &R)
+where
+    T: PartialEq + Glyph,
+    L: MatrixView<Item = T>,
+    R: MatrixView<Item = T>,
+{
+    let diff = GridDiff::new(left, right);
+    if diff.is_same() {
+        return;
+    }
+    panic!(
+        "matrices differ: {} changed cells, left {} right {}\n{diff}",
+        diff.changed().len(),
+        left.size(),
+        right.size(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::matrix::Grid;
+    use super::*;
+
+    fn grids() -> (Grid<char>, Grid<char>) {
+        ("AB\nCD".parse().unwrap(), "AX\nCD".parse().unwrap())
+    }
+
+    #[test]
+    fn side_by_side_test() {
+        let (left, right) = grids();
+        let diff = GridDiff::new(&left, &right);
+        assert_eq!(diff.changed(), vec![UVec2::new(1, 0)]);
+        assert_eq!(diff.to_string(), format!("A{RED}B{RESET} | A{GREEN}X{RESET}\nCD | CD\n"));
+        assert!(GridDiff::new(&left, &left).is_same());
+    }
+
+    #[test]
+    fn merged_test() {
+        let (left, right) = grids();
+        let diff = GridDiff::new(&left, &right).layout(DiffLayout::Merged);
+        assert_eq!(diff.to_string(), format!("A{GREEN}X{RESET}\nCD\n"));
+
+        let narrow = vec![vec!['A'], vec!['C']];
+        let diff = GridDiff::new(&left, &narrow).layout(DiffLayout::Merged);
+        assert_eq!(diff.size(), UVec2::new(2, 2));
+        assert_eq!(diff.to_string(), format!("A{RED}B{RESET}\nC{RED}D{RESET}\n"));
+        assert!(!diff.is_same());
+    }
+
+    #[test]
+    fn assert_matrix_eq_test() {
+        let (left, right) = grids();
+        assert_matrix_eq(&left, &left.to_matrix());
+        let panic = std::panic::catch_unwind(|| assert_matrix_eq(&left, &right)).unwrap_err();
+        let message = panic.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("matrices differ: 1 changed cells, left [2, 2] right [2, 2]\n"));
+        assert!(message.ends_with(&format!("A{RED}B{RESET} | A{GREEN}X{RESET}\nCD | CD\n")));
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use glam::{IVec2, UVec2};
+use crate::utils::matrix::{label_matching, Connectivity, Grid, MatrixDetails, Regions};
+
+const SIDES: [IVec2; 4] = [IVec2::NEG_Y, IVec2::X, IVec2::Y, IVec2::NEG_X];
+const DIAGONALS: [IVec2; 4] = [IVec2::new(-1, -1), IVec2::new(1, -1), IVec2::new(1, 1), IVec2::new(-1, 1)];
+
+/// shape of a single labeled region
+///
+/// cell `(x, y)` covers `(x, y)..(x + 1, y + 1)`, so outline vertices sit on the corners between cells
+#[derive(Debug, Copy, Clone)]
+pub struct RegionGeometry<'a> {
+    regions: &'a Regions,
+    label: usize,
+    cells: &'a [UVec2],
+}
+
+impl Regions {
+    pub fn geometry(&self, label: usize) -> Option<RegionGeometry<'_>> {
+        let cells = self.region(label)?;
+        Some(RegionGeometry { regions: self, label, cells })
+    }
+}
+
+impl<'a> RegionGeometry<'a> {
+    pub fn label(&self) -> usize {
+        self.label
+    }
+    pub fn cells(&self) -> &'a [UVec2] {
+        self.cells
+    }
+    pub fn contains(&self, pos: IVec2) -> bool {
+        self.regions.labels().get(pos).copied().flatten() == Some(self.label)
+    }
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+    /// number of cell edges that don't touch another cell of the region, holes included
+    pub fn perimeter(&self) -> usize {
+        self.boundary().count()
+    }
+    /// convex and concave corners of the outline, holes included
+    ///
+    /// a point where two region cells only touch diagonally counts as two corners, one for each cell
+    pub fn corners(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|pos| pos.as_ivec2())
+            .map(|pos| {
+                DIAGONALS
+                    .iter()
+                    .filter(|diagonal| {
+                        let horizontal = self.contains(pos + diagonal.with_y(0));
+                        let vertical = self.contains(pos + diagonal.with_x(0));
+                        let across = self.contains(pos + **diagonal);
+                        (!horizontal && !vertical) || (horizontal && vertical && !across)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+    /// number of straight fence sides, every side ends in exactly one corner
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+    pub fn bounds(&self) -> MatrixDetails {
+        MatrixDetails::from_positions(self.cells.iter().map(|pos| pos.as_ivec2()))
+    }
+    /// groups of cells the region encloses, matching the hole loops of [`RegionGeometry::outline`]
+    /// so a diagonal gap between two region cells doesn't lead outside
+    pub fn holes(&self) -> Vec<Vec<UVec2>> {
+        let bounds = self.bounds();
+        // one cell of padding so everything outside the region connects around it
+        let origin = bounds.min() - IVec2::ONE;
+        let padded = Grid::from_fn(bounds.width() + 2, bounds.height() + 2, |pos| {
+            self.contains(origin + pos.as_ivec2())
+        });
+        let outside = label_matching(&padded, Connectivity::Four, |&in_region| !in_region);
+        outside
+            .iter()
+            .skip(1)
+            .map(|(_, cells)| cells.iter().map(|pos| (origin + pos.as_ivec2()).as_uvec2()).collect())
+            .collect()
+    }
+    /// closed loops of outline vertices, the outer boundary first then one loop per hole
+    ///
+    /// loops run clockwise with the region on their right and only list the vertices where the outline turns
+    pub fn outline(&self) -> Vec<Vec<IVec2>> {
+        let mut edges = HashMap::<IVec2, Vec<IVec2>>::new();
+        for (start, direction) in self.boundary() {
+            edges.entry(start).or_default().push(direction);
+        }
+        let mut starts = edges.keys().copied().collect::<Vec<_>>();
+        starts.sort_by_key(|pos| (pos.y, pos.x));
+
+        let mut loops = vec![];
+        for start in starts {
+            let Some(&first) = edges.get(&start).and_then(|directions| directions.first()) else {
+                continue;
+            };
+            let mut vertices = vec![];
+            let mut pos = start;
+            let mut direction = first;
+            loop {
+                let directions = edges.get_mut(&pos).expect("outline edges form closed loops");
+                let ix = directions.iter().position(|&next| next == direction).expect("outline edges form closed loops");
+                directions.swap_remove(ix);
+                if directions.is_empty() {
+                    edges.remove(&pos);
+                }
+                pos += direction;
+                let Some(directions) = edges.get(&pos) else {
+                    break;
+                };
+                // turning left first stays on the region at diagonal gaps, so holes that touch diagonally
+                // get their own loop like they do in `holes`
+                let right = IVec2::new(-direction.y, direction.x);
+                let left = -right;
+                let next = [left, direction, right]
+                    .into_iter()
+                    .find(|next| directions.contains(next))
+                    .expect("outline edges form closed loops");
+                if next != direction {
+                    vertices.push(pos);
+                }
+                direction = next;
+            }
+            if direction != first {
+                vertices.insert(0, start);
+            }
+            loops.push(vertices);
+        }
+        loops
+    }
+    /// every cell edge on the outline as its clockwise start vertex and direction
+    fn boundary(&self) -> impl Iterator<Item = (IVec2, IVec2)> + '_ {
+        self.cells.iter().map(|pos| pos.as_ivec2()).flat_map(move |pos| {
+            SIDES.into_iter().filter(move |side| !self.contains(pos + *side)).map(move |side| {
+                // walk each edge clockwise, top left to top right for the top edge and so on
+                let start = match side {
+                    IVec2::NEG_Y => pos,
+                    IVec2::X => pos + IVec2::X,
+                    IVec2::Y => pos + IVec2::ONE,
+                    _ => pos + IVec2::Y,
+                };
+                (start, IVec2::new(-side.y, side.x))
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::matrix::label_regions;
+    use crate::utils::string::deformat_string;
+    use super::*;
+
+    fn regions(garden: &str) -> (Grid<char>, Regions) {
+        let garden = deformat_string(garden).parse::<Grid<char>>().unwrap();
+        let regions = label_regions(&garden, Connectivity::Four);
+        (garden, regions)
+    }
+
+    /// (label, area, perimeter, sides) per region
+    fn measure(garden: &str) -> Vec<(char, usize, usize, usize)> {
+        let (garden, regions) = regions(garden);
+        regions
+            .iter()
+            .map(|(label, cells)| {
+                let geometry = regions.geometry(label).unwrap();
+                assert_eq!(geometry.outline().iter().map(Vec::len).sum::<usize>(), geometry.corners());
+                (garden[cells[0]], geometry.area(), geometry.perimeter(), geometry.sides())
+            })
+            .collect()
+    }
+
+    fn costs(measured: &[(char, usize, usize, usize)]) -> (usize, usize) {
+        measured.iter().fold((0, 0), |(perimeter_cost, sides_cost), (_, area, perimeter, sides)| {
+            (perimeter_cost + area * perimeter, sides_cost + area * sides)
+        })
+    }
+
+    #[test]
+    fn day12_small_example_test() {
+        let measured = measure("
+            AAAA
+            BBCD
+            BBCC
+            EEEC
+        ");
+        assert_eq!(measured, vec![
+            ('A', 4, 10, 4),
+            ('B', 4, 8, 4),
+            ('C', 4, 10, 8),
+            ('D', 1, 4, 4),
+            ('E', 3, 8, 4),
+        ]);
+        assert_eq!(costs(&measured), (140, 80));
+    }
+
+    #[test]
+    fn day12_holes_example_test() {
+        let garden = "
+            OOOOO
+            OXOXO
+            OOOOO
+            OXOXO
+            OOOOO
+        ";
+        assert_eq!(costs(&measure(garden)), (772, 436));
+
+        let (_, regions) = regions(garden);
+        let outer = regions.geometry(0).unwrap();
+        assert_eq!(outer.holes().len(), 4);
+        assert_eq!(outer.holes()[0], vec![UVec2::new(1, 1)]);
+        assert_eq!(outer.outline().len(), 5);
+        assert_eq!(outer.outline()[0], vec![IVec2::new(0, 0), IVec2::new(5, 0), IVec2::new(5, 5), IVec2::new(0, 5)]);
+        assert_eq!(outer.bounds(), MatrixDetails::from_size(IVec2::ZERO, UVec2::splat(5)));
+        assert!(regions.geometry(1).unwrap().holes().is_empty());
+    }
+
+    #[test]
+    fn day12_larger_example_test() {
+        let measured = measure("
+            RRRRIICCFF
+            RRRRIICCCF
+            VVRRRCCFFF
+            VVRCCCJFFF
+            VVVVCJJCFE
+            VVIVCCJJEE
+            VVIIICJJEE
+            MIIIIIJJEE
+            MIIISIJEEE
+            MMMISSJEEE
+        ");
+        assert_eq!(measured.len(), 11);
+        assert_eq!(measured[0], ('R', 12, 18, 10));
+        assert_eq!(costs(&measured), (1930, 1206));
+    }
+
+    #[test]
+    fn day12_part2_examples_test() {
+        assert_eq!(costs(&measure("
+            EEEEE
+            EXXXX
+            EEEEE
+            EXXXX
+            EEEEE
+        ")).1, 236);
+
+        let garden = "
+            AAAAAA
+            AAABBA
+            AAABBA
+            ABBAAA
+            ABBAAA
+            AAAAAA
+        ";
+        let measured = measure(garden);
+        assert_eq!(measured[0], ('A', 28, 40, 12));
+        assert_eq!(costs(&measured).1, 368);
+
+        // the two B blocks only touch diagonally, so they are separate holes in A
+        let (_, regions) = regions(garden);
+        let outer = regions.geometry(0).unwrap();
+        assert_eq!(outer.holes().len(), 2);
+        assert_eq!(outer.outline().len(), 3);
+    }
+}
@@ -0,0 +1,344 @@
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+use glam::{IVec2, UVec2};
+use thiserror::Error;
+use crate::utils::matrix::get_matrix_width;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    #[error("expected {expected} cells for {width}x{height}, got {actual}")]
+    SizeMismatch { width: usize, height: usize, expected: usize, actual: usize },
+    #[error("row {row} has {actual} cells, expected {expected}")]
+    RaggedRow { row: usize, expected: usize, actual: usize },
+}
+
+/// positions that can address a cell of a [`Grid`], out of bounds (or negative) positions give `None`
+pub trait GridIndex: Copy {
+    fn grid_index(self, width: usize, height: usize) -> Option<usize>;
+}
+
+impl GridIndex for UVec2 {
+    fn grid_index(self, width: usize, height: usize) -> Option<usize> {
+        let (x, y) = (self.x as usize, self.y as usize);
+        if x >= width || y >= height {
+            return None;
+        }
+        Some(y * width + x)
+    }
+}
+
+impl GridIndex for IVec2 {
+    fn grid_index(self, width: usize, height: usize) -> Option<usize> {
+        let x = usize::try_from(self.x).ok()?;
+        let y = usize::try_from(self.y).ok()?;
+        if x >= width || y >= height {
+            return None;
+        }
+        Some(y * width + x)
+    }
+}
+
+/// `(x, y)`
+impl GridIndex for (usize, usize) {
+    fn grid_index(self, width: usize, height: usize) -> Option<usize> {
+        let (x, y) = self;
+        if x >= width || y >= height {
+            return None;
+        }
+        Some(y * width + x)
+    }
+}
+
+/// row major matrix backed by a single contiguous `Vec`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, GridError> {
+        let expected = width * height;
+        if cells.len() != expected {
+            return Err(GridError::SizeMismatch { width, height, expected, actual: cells.len() });
+        }
+        Ok(Self { width, height, cells })
+    }
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(UVec2) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| UVec2::new(x as u32, y as u32)))
+            .map(&mut f)
+            .collect();
+        Self { width, height, cells }
+    }
+    /// builds a grid from lines of text, mapping each char
+    pub fn parse_with(text: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut cells = vec![];
+        let mut height = 0;
+        for (row, line) in text.lines().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let actual = cells.len() - before;
+            match width {
+                None => width = Some(actual),
+                Some(expected) if expected != actual => return Err(GridError::RaggedRow { row, expected, actual }),
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Self::new(width.unwrap_or(0), height, cells)
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn size(&self) -> UVec2 {
+        UVec2::new(self.width as u32, self.height as u32)
+    }
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+    pub fn contains(&self, pos: impl GridIndex) -> bool {
+        self.index_of(pos).is_some()
+    }
+    pub fn index_of(&self, pos: impl GridIndex) -> Option<usize> {
+        pos.grid_index(self.width, self.height)
+    }
+    pub fn pos_of(&self, index: usize) -> Option<UVec2> {
+        if index >= self.cells.len() {
+            return None;
+        }
+        Some(UVec2::new((index % self.width) as u32, (index / self.width) as u32))
+    }
+    pub fn get(&self, pos: impl GridIndex) -> Option<&T> {
+        self.index_of(pos).map(|ix| &self.cells[ix])
+    }
+    pub fn get_mut(&mut self, pos: impl GridIndex) -> Option<&mut T> {
+        self.index_of(pos).map(|ix| &mut self.cells[ix])
+    }
+    /// replaces the cell, returning the previous value, `None` when out of bounds
+    pub fn set(&mut self, pos: impl GridIndex, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y >= self.height {
+            return None;
+        }
+        Some(&self.cells[y * self.width..(y + 1) * self.width])
+    }
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        if y >= self.height {
+            return None;
+        }
+        Some(&mut self.cells[y * self.width..(y + 1) * self.width])
+    }
+    /// the cells of column `x` from top to bottom
+    pub fn column(&self, x: usize) -> Option<impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        if x >= self.width {
+            return None;
+        }
+        Some(self.cells[x..].iter().step_by(self.width).take(self.height))
+    }
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // chunks_exact panics on 0, a zero width grid has no cells so any chunk size yields nothing
+        self.cells.chunks_exact(self.width.max(1))
+    }
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+    /// every cell with its position in row major order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (UVec2, &T)> + ExactSizeIterator {
+        let width = self.width.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(ix, cell)| (UVec2::new((ix % width) as u32, (ix / width) as u32), cell))
+    }
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+    pub fn from_matrix(matrix: &[Vec<T>]) -> Result<Self, GridError>
+    where
+        T: Clone,
+    {
+        Self::try_from(matrix.to_vec())
+    }
+    pub fn to_matrix(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.rows().map(<[T]>::to_vec).collect()
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = GridError;
+
+    fn try_from(matrix: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let height = matrix.len();
+        let width = matrix.first().map_or(0, Vec::len);
+        if get_matrix_width(&matrix).is_none() && height > 0 {
+            let (row, actual) = matrix
+                .iter()
+                .enumerate()
+                .map(|(row, line)| (row, line.len()))
+                .find(|(_, len)| *len != width)
+                .expect("ragged matrix has a row of a different width");
+            return Err(GridError::RaggedRow { row, expected: width, actual });
+        }
+        Self::new(width, height, matrix.into_iter().flatten().collect())
+    }
+}
+
+impl<T> From<Grid<T>> for Vec<Vec<T>> {
+    fn from(grid: Grid<T>) -> Self {
+        let width = grid.width.max(1);
+        let mut cells = grid.cells.into_iter();
+        (0..grid.height).map(|_| cells.by_ref().take(width).collect()).collect()
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| c)
+    }
+}
+
+impl<T, P: GridIndex> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: P) -> &Self::Output {
+        let Some(ix) = self.index_of(pos) else {
+            panic!("position out of bounds for {}x{} grid", self.width, self.height);
+        };
+        &self.cells[ix]
+    }
+}
+
+impl<T, P: GridIndex> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, pos: P) -> &mut Self::Output {
+        let Some(ix) = self.index_of(pos) else {
+            panic!("position out of bounds for {}x{} grid", self.width, self.height);
+        };
+        &mut self.cells[ix]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::string::deformat_string;
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        deformat_string("
+            ABC
+            DEF
+        ").parse().unwrap()
+    }
+
+    #[test]
+    fn parse_test() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.size(), UVec2::new(3, 2));
+        assert_eq!(grid.cells(), &['A', 'B', 'C', 'D', 'E', 'F']);
+        assert_eq!(
+            "AB\nC".parse::<Grid<char>>(),
+            Err(GridError::RaggedRow { row: 1, expected: 2, actual: 1 }),
+        );
+        assert_eq!(Grid::parse_with("12\n34", |c| c.to_digit(10).unwrap()).unwrap().cells(), &[1, 2, 3, 4]);
+        assert!("".parse::<Grid<char>>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn get_test() {
+        let mut grid = grid();
+        assert_eq!(grid.get(UVec2::new(2, 1)), Some(&'F'));
+        assert_eq!(grid.get(UVec2::new(3, 0)), None);
+        assert_eq!(grid.get(IVec2::new(1, 0)), Some(&'B'));
+        assert_eq!(grid.get(IVec2::new(-1, 0)), None);
+        assert_eq!(grid.get((0, 1)), Some(&'D'));
+        assert_eq!(grid[UVec2::new(1, 1)], 'E');
+
+        *grid.get_mut(IVec2::new(0, 0)).unwrap() = 'a';
+        assert_eq!(grid.set(UVec2::new(1, 0), 'b'), Some('B'));
+        assert_eq!(grid.set(UVec2::new(9, 9), 'z'), None);
+        grid[(2, 0)] = 'c';
+        assert_eq!(grid.row(0), Some(&['a', 'b', 'c'][..]));
+    }
+
+    #[test]
+    fn index_pos_test() {
+        let grid = grid();
+        for ix in 0..grid.len() {
+            assert_eq!(grid.index_of(grid.pos_of(ix).unwrap()), Some(ix));
+        }
+        assert_eq!(grid.pos_of(6), None);
+        assert_eq!(grid.iter().nth(4), Some((UVec2::new(1, 1), &'E')));
+    }
+
+    #[test]
+    fn row_column_test() {
+        let mut grid = grid();
+        assert_eq!(grid.row(1), Some(&['D', 'E', 'F'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(1).unwrap().collect::<Vec<_>>(), vec![&'B', &'E']);
+        assert_eq!(grid.column(2).unwrap().rev().collect::<Vec<_>>(), vec![&'F', &'C']);
+        assert!(grid.column(3).is_none());
+        assert_eq!(grid.rows().count(), 2);
+        grid.row_mut(0).unwrap().reverse();
+        assert_eq!(grid.row(0), Some(&['C', 'B', 'A'][..]));
+    }
+
+    #[test]
+    fn matrix_conversion_test() {
+        let matrix = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let grid = Grid::try_from(matrix.clone()).unwrap();
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.to_matrix(), matrix);
+        assert_eq!(Vec::<Vec<i32>>::from(grid), matrix);
+        assert_eq!(
+            Grid::try_from(vec![vec![1, 2], vec![3]]),
+            Err(GridError::RaggedRow { row: 1, expected: 2, actual: 1 }),
+        );
+        assert_eq!(Grid::<i32>::try_from(vec![]).unwrap().to_matrix(), Vec::<Vec<i32>>::new());
+        assert_eq!(Grid::from_matrix(&matrix).unwrap().map(|n| n * 2).cells(), &[2, 4, 6, 8, 10, 12]);
+    }
+
+    #[test]
+    fn from_fn_test() {
+        let grid = Grid::from_fn(2, 2, |pos| pos.x + pos.y * 10);
+        assert_eq!(grid.cells(), &[0, 1, 10, 11]);
+        assert_eq!(Grid::filled(2, 1, '.').cells(), &['.', '.']);
+        assert!(matches!(Grid::new(2, 2, vec![1]), Err(GridError::SizeMismatch { expected: 4, actual: 1, .. })));
+    }
+}
@@ -0,0 +1,254 @@
+use std::iter::FusedIterator;
+use glam::{IVec2, UVec2};
+use crate::utils::matrix::MatrixView;
+
+/// which way a line runs through a matrix, reverse a line to walk it the other way
+///
+/// example:
+///     matrix: ABC
+///             DEF
+///     Row:          ABC, DEF
+///     Column:       AD, BE, CF
+///     Diagonal:     D, AE, BF, C
+///     AntiDiagonal: A, BD, CE, F
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LineKind {
+    /// left to right, top row first
+    Row,
+    /// top to bottom, left column first
+    Column,
+    /// top left to bottom right, starting from the bottom left corner
+    Diagonal,
+    /// top right to bottom left, starting from the top left corner
+    AntiDiagonal,
+}
+
+impl LineKind {
+    pub const ALL: [LineKind; 4] = [LineKind::Row, LineKind::Column, LineKind::Diagonal, LineKind::AntiDiagonal];
+
+    /// offset between two neighboring cells of a line
+    pub fn step(self) -> IVec2 {
+        match self {
+            LineKind::Row => IVec2::X,
+            LineKind::Column => IVec2::Y,
+            LineKind::Diagonal => IVec2::ONE,
+            LineKind::AntiDiagonal => IVec2::new(-1, 1),
+        }
+    }
+    /// number of lines of this kind in a `size` matrix
+    pub fn count(self, size: UVec2) -> usize {
+        if size.cmpeq(UVec2::ZERO).any() {
+            return 0;
+        }
+        match self {
+            LineKind::Row => size.y as usize,
+            LineKind::Column => size.x as usize,
+            LineKind::Diagonal | LineKind::AntiDiagonal => (size.x + size.y - 1) as usize,
+        }
+    }
+    /// first cell and length of a line, `None` past the last line
+    fn bounds(self, size: UVec2, index: usize) -> Option<(UVec2, usize)> {
+        if index >= self.count(size) {
+            return None;
+        }
+        let index = index as u32;
+        let start = match self {
+            LineKind::Row => UVec2::new(0, index),
+            LineKind::Column => UVec2::new(index, 0),
+            LineKind::Diagonal => UVec2::new(index.saturating_sub(size.y - 1), (size.y - 1).saturating_sub(index)),
+            LineKind::AntiDiagonal => UVec2::new(index.min(size.x - 1), index - index.min(size.x - 1)),
+        };
+        let len = match self {
+            LineKind::Row => size.x,
+            LineKind::Column => size.y,
+            LineKind::Diagonal => (size.x - start.x).min(size.y - start.y),
+            LineKind::AntiDiagonal => (start.x + 1).min(size.y - start.y),
+        };
+        Some((start, len as usize))
+    }
+}
+
+/// cells of a single line with their position,
+/// walks from both ends so `rev()` gives the opposite direction
+///
+/// cells the view doesn't map (the gaps of a skew) are skipped
+#[derive(Debug)]
+pub struct Line<'a, V> {
+    view: &'a V,
+    start: UVec2,
+    step: IVec2,
+    front: usize,
+    back: usize,
+}
+
+impl<V> Clone for Line<'_, V> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, V: MatrixView> Line<'a, V> {
+    pub fn new(view: &'a V, kind: LineKind, index: usize) -> Option<Self> {
+        let (start, len) = kind.bounds(view.size(), index)?;
+        Some(Self { view, start, step: kind.step(), front: 0, back: len })
+    }
+    fn pos(&self, offset: usize) -> UVec2 {
+        (self.start.as_ivec2() + self.step * offset as i32).as_uvec2()
+    }
+}
+
+impl<'a, V: MatrixView> Iterator for Line<'a, V> {
+    type Item = (UVec2, &'a V::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            let pos = self.pos(self.front);
+            self.front += 1;
+            if let Some(cell) = self.view.cell(pos) {
+                return Some((pos, cell));
+            }
+        }
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.back - self.front))
+    }
+}
+
+impl<V: MatrixView> DoubleEndedIterator for Line<'_, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            self.back -= 1;
+            let pos = self.pos(self.back);
+            if let Some(cell) = self.view.cell(pos) {
+                return Some((pos, cell));
+            }
+        }
+        None
+    }
+}
+
+impl<V: MatrixView> FusedIterator for Line<'_, V> {}
+
+/// every line of one kind, in the order described on [`LineKind`]
+#[derive(Debug)]
+pub struct Lines<'a, V> {
+    view: &'a V,
+    kind: LineKind,
+    front: usize,
+    back: usize,
+}
+
+impl<V> Clone for Lines<'_, V> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, V: MatrixView> Lines<'a, V> {
+    pub fn new(view: &'a V, kind: LineKind) -> Self {
+        Self { view, kind, front: 0, back: kind.count(view.size()) }
+    }
+}
+
+impl<'a, V: MatrixView> Iterator for Lines<'a, V> {
+    type Item = Line<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Line::new(self.view, self.kind, self.front - 1)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+
+impl<V: MatrixView> DoubleEndedIterator for Lines<'_, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Line::new(self.view, self.kind, self.back)
+    }
+}
+
+impl<V: MatrixView> ExactSizeIterator for Lines<'_, V> {}
+
+impl<V: MatrixView> FusedIterator for Lines<'_, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::matrix::Grid;
+    use crate::utils::string::deformat_string;
+    use super::*;
+
+    fn collect<V: MatrixView<Item = char>>(view: &V, kind: LineKind) -> Vec<String> {
+        view.lines(kind).map(|line| line.map(|(_, c)| *c).collect()).collect()
+    }
+
+    #[test]
+    fn lines_test() {
+        let grid = "ABC\nDEF".parse::<Grid<char>>().unwrap();
+        assert_eq!(collect(&grid, LineKind::Row), vec!["ABC", "DEF"]);
+        assert_eq!(collect(&grid, LineKind::Column), vec!["AD", "BE", "CF"]);
+        assert_eq!(collect(&grid, LineKind::Diagonal), vec!["D", "AE", "BF", "C"]);
+        assert_eq!(collect(&grid, LineKind::AntiDiagonal), vec!["A", "BD", "CE", "F"]);
+
+        let tall = grid.view().transpose();
+        assert_eq!(collect(&tall, LineKind::Diagonal), vec!["C", "BF", "AE", "D"]);
+        assert_eq!(collect(&tall, LineKind::AntiDiagonal), vec!["A", "DB", "EC", "F"]);
+        assert!(Grid::<char>::filled(0, 3, '.').lines(LineKind::Column).next().is_none());
+    }
+
+    #[test]
+    fn double_ended_test() {
+        let matrix = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+        let mut line = matrix.line(LineKind::Diagonal, 2).unwrap();
+        assert_eq!(line.next(), Some((UVec2::new(0, 0), &1)));
+        assert_eq!(line.next_back(), Some((UVec2::new(2, 2), &9)));
+        assert_eq!(line.next_back(), Some((UVec2::new(1, 1), &5)));
+        assert_eq!(line.next(), None);
+        assert_eq!(line.next_back(), None);
+
+        let reversed = matrix.line(LineKind::AntiDiagonal, 3).unwrap().rev().collect::<Vec<_>>();
+        assert_eq!(reversed, vec![(UVec2::new(1, 2), &8), (UVec2::new(2, 1), &6)]);
+        assert_eq!(matrix.lines(LineKind::Row).next_back().unwrap().map(|(_, v)| *v).sum::<i32>(), 24);
+        assert_eq!(matrix.lines(LineKind::Diagonal).len(), 5);
+        assert!(matrix.line(LineKind::Column, 3).is_none());
+    }
+
+    #[test]
+    fn skewed_lines_skip_gaps_test() {
+        let matrix = vec![vec!['A', 'B'], vec!['C', 'D']];
+        let skewed = matrix.view().skew(1);
+        assert_eq!(collect(&skewed, LineKind::Column), vec!["AC", "BD"]);
+        assert_eq!(collect(&skewed, LineKind::Row), vec!["A", "CB", "D"]);
+    }
+
+    #[test]
+    fn xmas_line_scan_test() {
+        let grid = deformat_string("
+            MMMSXXMASM
+            MSAMXMSMSA
+            AMXSXMAAMM
+            MSAMASMSMX
+            XMASAMXAMM
+            XXAMMXXAMA
+            SMSMSASXSS
+            SAXAMASAAA
+            MAMMMXMMMM
+            MXMXAXMASX
+        ").parse::<Grid<char>>().unwrap();
+        let count = |line: String| line.matches("XMAS").count() + line.matches("SAMX").count();
+        let found = LineKind::ALL
+            .into_iter()
+            .flat_map(|kind| grid.lines(kind))
+            .map(|line| count(line.map(|(_, c)| *c).collect()))
+            .sum::<usize>();
+        assert_eq!(found, 18);
+    }
+}
@@ -1,3 +1,31 @@
+mod details;
+mod diff;
+mod geometry;
+mod grid;
+mod lines;
+mod parse;
+mod pattern;
+mod regions;
+mod render;
+mod sparse;
+mod view;
+mod windows;
+mod wrap;
+
+pub use details::MatrixDetails;
+pub use diff::{assert_matrix_eq, DiffLayout, GridDiff};
+pub use geometry::RegionGeometry;
+pub use grid::{Grid, GridError, GridIndex};
+pub use lines::{Line, LineKind, Lines};
+pub use parse::{parse_grid, parse_grid_cells, ParseGridError};
+pub use pattern::{find_pattern, PatternMatch, PatternSet, Transform};
+pub use regions::{label_matching, label_regions, label_regions_by, Connectivity, Regions};
+pub use render::{Glyph, GridRender, RenderGrid};
+pub use sparse::{SparseGrid, SparseGridError};
+pub use view::{FlipHorizontal, FlipVertical, MatrixView, Rotate, Skew, SubRect, Transpose};
+pub use windows::Windows;
+pub use wrap::Torus;
+
 use glam::UVec2;
 
 pub fn get_matrix_width<T>(matrix: &Vec<Vec<T>>) -> Option<usize> {
@@ -81,26 +109,15 @@ pub fn extend_matrix_by<T: Clone + Default>(matrix: &mut Vec<Vec<T>>, amount: i3
     if amount == 0 {
         return;
     }
-    if !is_valid_matrix_dimensions(&matrix) {
+    let Some(width) = get_matrix_width(matrix) else {
         panic!("Invalid matrix dimensions");
-    }
-    fn fill<T: Clone>(matrix: &mut Vec<T>, amount: usize, value: T) {
-        for _ in 0..amount {
-            matrix.push(value.clone());
-        }
-    }
-
-    let (first, second) = match amount.signum() {
-        -1 => (1, 3),
-        1 => (3, 1),
-        // unreachable because of the amount == 0 check at the top
-        _ => unreachable!(),
     };
-    *matrix = rotate_matrix_n_times(matrix, first);
-    for line in matrix.iter_mut() {
-        fill(line, amount.abs() as usize, T::default());
+    let rows = std::iter::repeat_with(|| vec![T::default(); width]).take(amount.unsigned_abs() as usize);
+    if amount > 0 {
+        matrix.extend(rows);
+    } else {
+        matrix.splice(0..0, rows);
     }
-    *matrix = rotate_matrix_n_times(matrix, second);
 }
 
 /// returns skewed matrix by the amount,
@@ -183,72 +200,12 @@ where T: Clone + Default + PartialEq,
     diff
 }
 
-#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
-pub struct MatrixDetails {
-    min: UVec2,
-    max: UVec2,
-}
-
-impl MatrixDetails {
-    pub fn from_matrix<T>(matrix: &Vec<Vec<T>>) -> Self {
-        let mut min = UVec2::MAX;
-        let mut max = UVec2::MIN;
-        for (pos, _) in MatrixIterator::new(matrix) {
-            min = min.min(pos);
-            max = max.max(pos);
-        }
-        Self {
-            min,
-            max,
-        }
-    }
-    pub fn min(&self) -> UVec2 {
-        self.min
-    }
-    pub fn max(&self) -> UVec2 {
-        self.max
-    }
-    pub fn width(&self) -> usize {
-        self.max.x as usize - self.min.x as usize
-    }
-    pub fn height(&self) -> usize {
-        self.max.y as usize - self.min.y as usize
-    }
-    pub fn max_index(&self) -> usize {
-        self.width() * self.height()
-    }
-    pub fn is_within_bounds(&self, pos: UVec2) -> bool {
-        if pos.x < self.min.x || pos.y < self.min.y {
-            return false;
-        }
-        if pos.x > self.max.x || pos.y > self.max.y {
-            return false;
-        }
-        true
-    }
-    pub fn pos_from_index(&self, index: usize) -> Option<UVec2> {
-        let x = index % self.width();
-        let y = index / self.width();
-        let pos = UVec2::new(x as u32, y as u32);
-        if !self.is_within_bounds(pos) {
-            return None;
-        }
-        Some(pos)
-    }
-    pub fn index_from_pos(&self, pos: UVec2) -> Option<usize> {
-        if !self.is_within_bounds(pos) {
-            return None;
-        }
-        let x = pos.x as usize - self.min.x as usize;
-        let y = pos.y as usize - self.min.y as usize;
-        Some(y * self.width() + x)
-    }
-}
-
 #[derive(Debug, Copy, Clone)]
 pub struct MatrixIterator<'a, T> {
     // TODO: this should be &'a [&'a [T]] but it would be a pita to update the helper functions right now
     matrix: &'a Vec<Vec<T>>,
+    /// validated once up front, `None` for ragged matrices
+    cols: Option<usize>,
     index: usize,
 }
 
@@ -256,6 +213,7 @@ impl<'a, T> MatrixIterator<'a, T> {
     pub fn new(matrix: &'a Vec<Vec<T>>) -> Self {
         Self {
             matrix,
+            cols: get_matrix_width(matrix),
             index: 0,
         }
     }
@@ -271,7 +229,7 @@ impl<'a, T> Iterator for MatrixIterator<'a, T> {
             return None;
         }
 
-        let Some(cols) = get_matrix_width(self.matrix) else {
+        let Some(cols) = self.cols else {
             return None;
         };
 
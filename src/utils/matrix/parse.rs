@@ -0,0 +1,117 @@
+use glam::UVec2;
+use thiserror::Error;
+use crate::utils::matrix::{Grid, GridError};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError<E> {
+    /// `row` and `col` count from 0 like every other position
+    #[error("invalid cell {char:?} at row {row}, column {col}: {error}")]
+    InvalidCell { row: usize, col: usize, char: char, error: E },
+    #[error(transparent)]
+    Grid(#[from] GridError),
+}
+
+impl<E> ParseGridError<E> {
+    /// position of the offending char, `None` for shape errors
+    pub fn pos(&self) -> Option<UVec2> {
+        match self {
+            ParseGridError::InvalidCell { row, col, .. } => Some(UVec2::new(*col as u32, *row as u32)),
+            ParseGridError::Grid(_) => None,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// builds a grid from lines of text, mapping each char and its position until the first error
+    pub fn try_parse_with<E>(
+        text: &str,
+        mut f: impl FnMut(UVec2, char) -> Result<T, E>,
+    ) -> Result<Self, ParseGridError<E>> {
+        let mut width = None;
+        let mut cells = vec![];
+        let mut height = 0;
+        for (row, line) in text.lines().enumerate() {
+            let before = cells.len();
+            for (col, char) in line.chars().enumerate() {
+                let cell = f(UVec2::new(col as u32, row as u32), char)
+                    .map_err(|error| ParseGridError::InvalidCell { row, col, char, error })?;
+                cells.push(cell);
+            }
+            let actual = cells.len() - before;
+            match width {
+                None => width = Some(actual),
+                Some(expected) if expected != actual => Err(GridError::RaggedRow { row, expected, actual })?,
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Self::new(width.unwrap_or(0), height, cells)?)
+    }
+}
+
+/// parses a grid through a fallible closure that also gets the position of each char
+///
+/// example:
+///     parse_grid("12\n3x", |_, c| c.to_digit(10).ok_or("not a digit"))
+///     result: Err(invalid cell 'x' at row 1, column 1: not a digit)
+pub fn parse_grid<T, E>(text: &str, f: impl FnMut(UVec2, char) -> Result<T, E>) -> Result<Grid<T>, ParseGridError<E>> {
+    Grid::try_parse_with(text, f)
+}
+
+/// parses a grid of cell types that know how to read themselves from a char
+pub fn parse_grid_cells<T: TryFrom<char>>(text: &str) -> Result<Grid<T>, ParseGridError<T::Error>> {
+    Grid::try_parse_with(text, |_, c| T::try_from(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::string::deformat_string;
+    use super::*;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = &'static str;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                _ => Err("expected '.' or '#'"),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_grid_test() {
+        let grid = parse_grid("12\n34", |pos, c| c.to_digit(10).map(|digit| (pos, digit)).ok_or("not a digit"));
+        let grid = grid.unwrap();
+        assert_eq!(grid[UVec2::new(1, 1)], (UVec2::new(1, 1), 4));
+
+        let error = parse_grid("12\n3x", |_, c| c.to_digit(10).ok_or("not a digit")).unwrap_err();
+        assert_eq!(error, ParseGridError::InvalidCell { row: 1, col: 1, char: 'x', error: "not a digit" });
+        assert_eq!(error.pos(), Some(UVec2::new(1, 1)));
+        assert_eq!(error.to_string(), "invalid cell 'x' at row 1, column 1: not a digit");
+    }
+
+    #[test]
+    fn parse_grid_cells_test() {
+        let grid = parse_grid_cells::<Tile>(&deformat_string("
+            .#
+            #.
+        ")).unwrap();
+        assert_eq!(grid.to_matrix(), vec![vec![Tile::Open, Tile::Wall], vec![Tile::Wall, Tile::Open]]);
+
+        let error = parse_grid_cells::<Tile>(".#\n.@").unwrap_err();
+        assert_eq!(error.pos(), Some(UVec2::new(1, 1)));
+        assert_eq!(
+            parse_grid_cells::<Tile>("..\n.").unwrap_err(),
+            ParseGridError::Grid(GridError::RaggedRow { row: 1, expected: 2, actual: 1 }),
+        );
+        assert!(parse_grid_cells::<Tile>("").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use glam::UVec2;
+use crate::utils::matrix::MatrixView;
+
+/// the eight symmetries of a rectangle, rotations are clockwise like [`super::rotate_matrix_n_times`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    /// mirrors left to right
+    FlipHorizontal,
+    /// mirrors top to bottom
+    FlipVertical,
+    /// mirrors across the main diagonal
+    Transpose,
+    /// mirrors across the anti diagonal
+    AntiTranspose,
+}
+
+impl Transform {
+    pub const ROTATIONS: [Transform; 4] = [
+        Transform::Identity,
+        Transform::Rotate90,
+        Transform::Rotate180,
+        Transform::Rotate270,
+    ];
+    pub const REFLECTIONS: [Transform; 4] = [
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+        Transform::Transpose,
+        Transform::AntiTranspose,
+    ];
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::Rotate90,
+        Transform::Rotate180,
+        Transform::Rotate270,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+        Transform::Transpose,
+        Transform::AntiTranspose,
+    ];
+
+    /// transformed copy of a pattern, cells past the end of a ragged row become wildcards
+    pub fn apply<T: Clone>(&self, pattern: &Vec<Vec<Option<T>>>) -> Vec<Vec<Option<T>>> {
+        let view = pattern.view();
+        match self {
+            Transform::Identity => view.to_matrix(),
+            Transform::Rotate90 => view.rotate_90().to_matrix(),
+            Transform::Rotate180 => view.rotate_180().to_matrix(),
+            Transform::Rotate270 => view.rotate_270().to_matrix(),
+            Transform::FlipHorizontal => view.flip_horizontal().to_matrix(),
+            Transform::FlipVertical => view.flip_vertical().to_matrix(),
+            Transform::Transpose => view.transpose().to_matrix(),
+            Transform::AntiTranspose => view.rotate_90().flip_vertical().to_matrix(),
+        }
+    }
+}
+
+/// a pattern found in a matrix,
+/// `origin` is the top left of the transformed pattern and `pattern` its index in the [`PatternSet`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PatternMatch {
+    pub pattern: usize,
+    pub transform: Transform,
+    pub origin: UVec2,
+    pub size: UVec2,
+}
+
+#[derive(Debug, Clone)]
+struct Variant<T> {
+    pattern: usize,
+    transform: Transform,
+    size: UVec2,
+    /// non wildcard cells, the first one anchors the variant
+    fixed: Vec<(UVec2, T)>,
+}
+
+/// patterns and their symmetries compiled for a single pass search
+///
+/// every variant is indexed by the value of its first fixed cell,
+/// so each matrix cell only checks the variants that could be anchored on it
+#[derive(Debug, Clone)]
+pub struct PatternSet<T> {
+    variants: Vec<Variant<T>>,
+    anchored: HashMap<T, Vec<usize>>,
+    /// variants made of wildcards only, they match wherever they fit
+    unanchored: Vec<usize>,
+}
+
+impl<T: Clone + Eq + Hash> PatternSet<T> {
+    /// `None` for `symmetries` only searches the patterns as given,
+    /// a transform that turns a pattern into one of its earlier variants is skipped so matches aren't reported twice
+    pub fn new(patterns: &[Vec<Vec<Option<T>>>], symmetries: Option<&[Transform]>) -> Self {
+        let symmetries = symmetries.unwrap_or(&[Transform::Identity]);
+        let mut variants = vec![];
+        for (pattern_ix, pattern) in patterns.iter().enumerate() {
+            let mut seen = vec![];
+            for &transform in symmetries {
+                let transformed = transform.apply(pattern);
+                if seen.contains(&transformed) {
+                    continue;
+                }
+                let size = transformed.size();
+                let fixed = transformed
+                    .iter_cells()
+                    .filter_map(|(pos, cell)| Some((pos, cell.clone()?)))
+                    .collect();
+                seen.push(transformed);
+                if size.cmpeq(UVec2::ZERO).any() {
+                    continue;
+                }
+                variants.push(Variant { pattern: pattern_ix, transform, size, fixed });
+            }
+        }
+        let mut anchored = HashMap::<T, Vec<usize>>::new();
+        let mut unanchored = vec![];
+        for (ix, variant) in variants.iter().enumerate() {
+            match variant.fixed.first() {
+                Some((_, value)) => anchored.entry(value.clone()).or_default().push(ix),
+                None => unanchored.push(ix),
+            }
+        }
+        Self { variants, anchored, unanchored }
+    }
+    /// number of compiled variants across all patterns
+    pub fn len(&self) -> usize {
+        self.variants.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.variants.is_empty()
+    }
+    /// every match ordered by origin (row major), then by pattern and transform
+    pub fn find(&self, matrix: &impl MatrixView<Item = T>) -> Vec<PatternMatch> {
+        let matrix_size = matrix.size();
+        let fits = |variant: &Variant<T>, origin: UVec2| (origin + variant.size).cmple(matrix_size).all();
+        let mut found = vec![];
+        for y in 0..matrix_size.y {
+            for x in 0..matrix_size.x {
+                let pos = UVec2::new(x, y);
+                for &ix in &self.unanchored {
+                    if fits(&self.variants[ix], pos) {
+                        found.push((pos, ix));
+                    }
+                }
+                let Some(candidates) = matrix.cell(pos).and_then(|value| self.anchored.get(value)) else {
+                    continue;
+                };
+                for &ix in candidates {
+                    let variant = &self.variants[ix];
+                    let (anchor, _) = variant.fixed[0];
+                    if anchor.cmpgt(pos).any() {
+                        continue;
+                    }
+                    let origin = pos - anchor;
+                    if !fits(variant, origin) {
+                        continue;
+                    }
+                    let matches = variant.fixed[1..]
+                        .iter()
+                        .all(|(offset, value)| matrix.cell(origin + *offset) == Some(value));
+                    if matches {
+                        found.push((origin, ix));
+                    }
+                }
+            }
+        }
+        found.sort_by_key(|&(origin, ix)| (origin.y, origin.x, ix));
+        found
+            .into_iter()
+            .map(|(origin, ix)| {
+                let variant = &self.variants[ix];
+                PatternMatch { pattern: variant.pattern, transform: variant.transform, origin, size: variant.size }
+            })
+            .collect()
+    }
+    /// matrix positions of the non wildcard cells of a match
+    pub fn matched_cells<'a>(&'a self, found: &PatternMatch) -> impl Iterator<Item = UVec2> + 'a {
+        let origin = found.origin;
+        self.variants
+            .iter()
+            .find(|variant| variant.pattern == found.pattern && variant.transform == found.transform)
+            .into_iter()
+            .flat_map(move |variant| variant.fixed.iter().map(move |(offset, _)| origin + *offset))
+    }
+}
+
+/// every match of `pattern` in `matrix`, `None` cells in the pattern are wildcards
+pub fn find_pattern<T: Clone + Eq + Hash>(
+    matrix: &impl MatrixView<Item = T>,
+    pattern: &Vec<Vec<Option<T>>>,
+    symmetries: Option<&[Transform]>,
+) -> Vec<PatternMatch> {
+    PatternSet::new(std::slice::from_ref(pattern), symmetries).find(matrix)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::string::{deformat_string, StringToCharsMatrix};
+    use super::*;
+
+    #[test]
+    fn transform_test() {
+        let pattern = vec![vec![Some(1), Some(2)], vec![Some(3), None]];
+        assert_eq!(Transform::Rotate90.apply(&pattern), vec![vec![Some(3), Some(1)], vec![None, Some(2)]]);
+        assert_eq!(Transform::FlipHorizontal.apply(&pattern), vec![vec![Some(2), Some(1)], vec![None, Some(3)]]);
+        assert_eq!(Transform::Transpose.apply(&pattern), vec![vec![Some(1), Some(3)], vec![Some(2), None]]);
+        assert_eq!(Transform::AntiTranspose.apply(&pattern), vec![vec![None, Some(2)], vec![Some(3), Some(1)]]);
+    }
+
+    #[test]
+    fn find_pattern_test() {
+        let matrix = deformat_string("
+            ABC
+            123
+            ABC
+        ").to_chars_matrix();
+        let pattern = vec![
+            vec![None, Some('B'), None],
+            vec![None, None, Some('3')],
+            vec![None, Some('B'), None],
+        ];
+        assert_eq!(find_pattern(&matrix, &pattern, None), vec![PatternMatch {
+            pattern: 0,
+            transform: Transform::Identity,
+            origin: UVec2::ZERO,
+            size: UVec2::splat(3),
+        }]);
+
+        let pattern = vec![vec![Some('2'), Some('3')]];
+        let found = find_pattern(&matrix, &pattern, Some(&Transform::ALL));
+        assert_eq!(
+            found.iter().map(|found| (found.origin, found.transform)).collect::<Vec<_>>(),
+            vec![(UVec2::new(1, 1), Transform::Identity)],
+        );
+        let reversed = vec![vec![Some('3'), Some('2')]];
+        assert_eq!(find_pattern(&matrix, &reversed, Some(&Transform::ROTATIONS))[0].transform, Transform::Rotate180);
+        assert!(find_pattern(&matrix, &reversed, None).is_empty());
+    }
+
+    #[test]
+    fn pattern_set_test() {
+        let matrix = deformat_string("
+            XMAS
+            MM..
+            A.A.
+            S..S
+        ").to_chars_matrix();
+        let xmas = vec![vec![Some('X'), Some('M'), Some('A'), Some('S')]];
+        let diagonal = xmas.view().skew(1).to_matrix();
+        let set = PatternSet::new(&[xmas, diagonal], Some(&Transform::ALL));
+        assert_eq!(set.len(), 8);
+        let found = set.find(&matrix);
+        assert_eq!(
+            found.iter().map(|found| (found.pattern, found.transform)).collect::<Vec<_>>(),
+            vec![(0, Transform::Identity), (0, Transform::Rotate90), (1, Transform::Identity)],
+        );
+        assert_eq!(
+            set.matched_cells(&found[2]).collect::<Vec<_>>(),
+            vec![UVec2::new(0, 0), UVec2::new(1, 1), UVec2::new(2, 2), UVec2::new(3, 3)],
+        );
+
+        let wildcards = PatternSet::new(&[vec![vec![None::<char>; 2]; 2]], None);
+        assert_eq!(wildcards.find(&matrix).len(), 9);
+    }
+}
@@ -0,0 +1,164 @@
+use glam::{IVec2, UVec2};
+use crate::utils::matrix::Grid;
+
+const ORTHOGONAL: [IVec2; 4] = [IVec2::NEG_Y, IVec2::X, IVec2::Y, IVec2::NEG_X];
+const ALL: [IVec2; 8] = [
+    IVec2::NEG_Y,
+    IVec2::new(1, -1),
+    IVec2::X,
+    IVec2::new(1, 1),
+    IVec2::Y,
+    IVec2::new(-1, 1),
+    IVec2::NEG_X,
+    IVec2::new(-1, -1),
+];
+
+/// which neighbors count as touching
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// up, right, down and left
+    #[default]
+    Four,
+    /// orthogonal and diagonal
+    Eight,
+}
+
+impl Connectivity {
+    pub fn offsets(self) -> &'static [IVec2] {
+        match self {
+            Connectivity::Four => &ORTHOGONAL,
+            Connectivity::Eight => &ALL,
+        }
+    }
+}
+
+/// connected cells of a grid grouped under a label,
+/// labels count up from 0 in the order their first cell appears (row major)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Regions {
+    labels: Grid<Option<usize>>,
+    regions: Vec<Vec<UVec2>>,
+}
+
+impl Regions {
+    /// label of every cell, `None` for cells that were left out
+    pub fn labels(&self) -> &Grid<Option<usize>> {
+        &self.labels
+    }
+    pub fn label(&self, pos: UVec2) -> Option<usize> {
+        self.labels.get(pos).copied().flatten()
+    }
+    /// number of regions
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+    /// cells of a region in the order they were reached, starting with its first cell in row major order
+    pub fn region(&self, label: usize) -> Option<&[UVec2]> {
+        self.regions.get(label).map(Vec::as_slice)
+    }
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (usize, &[UVec2])> + ExactSizeIterator {
+        self.regions.iter().map(Vec::as_slice).enumerate()
+    }
+    pub fn into_regions(self) -> Vec<Vec<UVec2>> {
+        self.regions
+    }
+}
+
+/// labels the cells accepted by `include`, two neighbors share a region when `connected` holds for them
+///
+/// every cell is labeled once and checks its neighbors once, so this is linear in the size of the grid
+pub fn label_regions_by<T>(
+    grid: &Grid<T>,
+    connectivity: Connectivity,
+    include: impl Fn(&T) -> bool,
+    connected: impl Fn(&T, &T) -> bool,
+) -> Regions {
+    let mut labels = Grid::filled(grid.width(), grid.height(), None);
+    let mut regions = vec![];
+    let mut stack = vec![];
+    for (start, value) in grid.iter() {
+        if labels[start].is_some() || !include(value) {
+            continue;
+        }
+        let label = regions.len();
+        let mut cells = vec![];
+        labels[start] = Some(label);
+        stack.push(start);
+        while let Some(pos) = stack.pop() {
+            cells.push(pos);
+            let value = &grid[pos];
+            for &offset in connectivity.offsets() {
+                let next = pos.as_ivec2() + offset;
+                let Some(next_value) = grid.get(next) else {
+                    continue;
+                };
+                if labels[next].is_some() || !include(next_value) || !connected(value, next_value) {
+                    continue;
+                }
+                labels[next] = Some(label);
+                stack.push(next.as_uvec2());
+            }
+        }
+        regions.push(cells);
+    }
+    Regions { labels, regions }
+}
+
+/// labels every cell, neighbors with equal values share a region
+pub fn label_regions<T: PartialEq>(grid: &Grid<T>, connectivity: Connectivity) -> Regions {
+    label_regions_by(grid, connectivity, |_| true, |a, b| a == b)
+}
+
+/// labels the cells matching `predicate`, any matching neighbors share a region
+pub fn label_matching<T>(grid: &Grid<T>, connectivity: Connectivity, predicate: impl Fn(&T) -> bool) -> Regions {
+    label_regions_by(grid, connectivity, predicate, |_, _| true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_regions_test() {
+        let grid = "AAB\nBAB\nBBA".parse::<Grid<char>>().unwrap();
+        let regions = label_regions(&grid, Connectivity::Four);
+        assert_eq!(regions.len(), 4);
+        assert_eq!(regions.labels().to_matrix(), vec![
+            vec![Some(0), Some(0), Some(1)],
+            vec![Some(2), Some(0), Some(1)],
+            vec![Some(2), Some(2), Some(3)],
+        ]);
+        assert_eq!(regions.region(0).map(<[UVec2]>::len), Some(3));
+        assert_eq!(regions.region(3), Some([UVec2::new(2, 2)].as_slice()));
+
+        let regions = label_regions(&grid, Connectivity::Eight);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions.label(UVec2::new(2, 2)), Some(0));
+        assert_eq!(regions.label(UVec2::new(3, 0)), None);
+    }
+
+    #[test]
+    fn label_matching_test() {
+        let grid = "#...\n.#..\n..##".parse::<Grid<char>>().unwrap();
+        let regions = label_matching(&grid, Connectivity::Four, |&c| c == '#');
+        assert_eq!(regions.len(), 3);
+        assert_eq!(regions.label(UVec2::new(1, 0)), None);
+        assert_eq!(
+            regions.iter().map(|(label, cells)| (label, cells.len())).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 1), (2, 2)],
+        );
+        assert_eq!(label_matching(&grid, Connectivity::Eight, |&c| c == '#').len(), 1);
+    }
+
+    #[test]
+    fn label_regions_by_test() {
+        let grid = Grid::from_matrix(&[vec![1u32, 2, 3, 7], vec![9, 9, 4, 8]]).unwrap();
+        let regions = label_regions_by(&grid, Connectivity::Four, |&v| v < 9, |a, b| a.abs_diff(*b) == 1);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions.region(0).map(<[UVec2]>::len), Some(4));
+        assert_eq!(regions.label(UVec2::new(3, 1)), Some(1));
+    }
+}
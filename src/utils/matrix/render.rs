@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use glam::{IVec2, UVec2};
+use crate::utils::matrix::MatrixView;
+
+/// the char a cell is drawn as
+pub trait Glyph {
+    fn glyph(&self) -> char;
+}
+
+impl Glyph for char {
+    fn glyph(&self) -> char {
+        *self
+    }
+}
+
+/// `#` for true, `.` for false
+impl Glyph for bool {
+    fn glyph(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+/// blank for `None`, same as [`crate::utils::string::CharsMatrixToString`]
+impl<T: Glyph> Glyph for Option<T> {
+    fn glyph(&self) -> char {
+        self.as_ref().map_or(' ', Glyph::glyph)
+    }
+}
+
+type GlyphFn<'a, T> = Box<dyn Fn(&T) -> char + 'a>;
+
+/// text rendering of a view with overlay layers drawn on top of the cells
+///
+/// overlays are layered cursor over highlights over path, no matter the order they were added in,
+/// later highlights draw over earlier ones
+///
+/// example:
+///     grid: ...
+///           .#.
+///     grid.render().path([(0, 0), (1, 0), (2, 0), (2, 1)]).cursor((2, 1), '@').rulers(true)
+///     result:   012
+///             0 >>v
+///             1 .#@
+pub struct GridRender<'a, V: MatrixView> {
+    view: &'a V,
+    glyph: GlyphFn<'a, V::Item>,
+    unmapped: char,
+    path: HashMap<UVec2, char>,
+    highlights: HashMap<UVec2, char>,
+    cursor: Option<(UVec2, char)>,
+    rulers: bool,
+}
+
+impl<'a, V: MatrixView> GridRender<'a, V> {
+    pub fn new(view: &'a V, glyph: impl Fn(&V::Item) -> char + 'a) -> Self {
+        Self {
+            view,
+            glyph: Box::new(glyph),
+            unmapped: ' ',
+            path: HashMap::new(),
+            highlights: HashMap::new(),
+            cursor: None,
+            rulers: false,
+        }
+    }
+    /// char for positions the view doesn't map, i.e. the gaps of a skew
+    pub fn unmapped(mut self, glyph: char) -> Self {
+        self.unmapped = glyph;
+        self
+    }
+    /// draws a path as arrows pointing to the next position, the last position keeps the arrow it was entered with,
+    /// steps that aren't to an orthogonal neighbor are drawn as `O`
+    pub fn path(mut self, path: impl IntoIterator<Item = UVec2>) -> Self {
+        let path = path.into_iter().collect::<Vec<_>>();
+        let steps = path.windows(2).map(|step| (step[0], step[1].as_ivec2() - step[0].as_ivec2()));
+        let last = path.last().map(|&pos| (pos, path.len().checked_sub(2).map_or(IVec2::ZERO, |ix| {
+            pos.as_ivec2() - path[ix].as_ivec2()
+        })));
+        for (pos, direction) in steps.chain(last) {
+            let glyph = match direction {
+                IVec2::NEG_Y => '^',
+                IVec2::X => '>',
+                IVec2::Y => 'v',
+                IVec2::NEG_X => '<',
+                _ => 'O',
+            };
+            self.path.insert(pos, glyph);
+        }
+        self
+    }
+    /// draws every position of a path with the same glyph
+    pub fn path_with(mut self, path: impl IntoIterator<Item = UVec2>, glyph: char) -> Self {
+        self.path.extend(path.into_iter().map(|pos| (pos, glyph)));
+        self
+    }
+    pub fn highlight(mut self, positions: impl IntoIterator<Item = UVec2>, glyph: char) -> Self {
+        self.highlights.extend(positions.into_iter().map(|pos| (pos, glyph)));
+        self
+    }
+    /// a single position drawn over everything else, replaces the previous cursor
+    pub fn cursor(mut self, pos: UVec2, glyph: char) -> Self {
+        self.cursor = Some((pos, glyph));
+        self
+    }
+    /// row numbers on the left and column numbers on top, columns past 9 are written top to bottom
+    pub fn rulers(mut self, rulers: bool) -> Self {
+        self.rulers = rulers;
+        self
+    }
+    /// glyph at a position with all overlays applied
+    pub fn glyph_at(&self, pos: UVec2) -> char {
+        if let Some((_, glyph)) = self.cursor.filter(|(cursor, _)| *cursor == pos) {
+            return glyph;
+        }
+        if let Some(&glyph) = self.highlights.get(&pos).or_else(|| self.path.get(&pos)) {
+            return glyph;
+        }
+        self.view.cell(pos).map_or(self.unmapped, |cell| (self.glyph)(cell))
+    }
+}
+
+fn digits(value: usize) -> usize {
+    value.checked_ilog10().unwrap_or(0) as usize + 1
+}
+
+impl<V: MatrixView> Display for GridRender<'_, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (width, height) = (self.view.width(), self.view.height());
+        let label_width = digits(height.saturating_sub(1));
+        if self.rulers {
+            let column_digits = digits(width.saturating_sub(1));
+            for place in (0..column_digits as u32).rev() {
+                write!(f, "{:label_width$} ", "")?;
+                for x in 0..width {
+                    let digit = x / 10usize.pow(place) % 10;
+                    let leading_zero = place > 0 && x < 10usize.pow(place);
+                    let glyph = if leading_zero { ' ' } else { char::from_digit(digit as u32, 10).unwrap_or(' ') };
+                    write!(f, "{glyph}")?;
+                }
+                writeln!(f)?;
+            }
+        }
+        for y in 0..height {
+            if self.rulers {
+                write!(f, "{y:>label_width$} ")?;
+            }
+            for x in 0..width {
+                write!(f, "{}", self.glyph_at(UVec2::new(x as u32, y as u32)))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// renders any view, see [`GridRender`]
+pub trait RenderGrid: MatrixView + Sized {
+    fn render(&self) -> GridRender<'_, Self>
+    where Self::Item: Glyph
+    {
+        GridRender::new(self, Glyph::glyph)
+    }
+    fn render_with<'a>(&'a self, glyph: impl Fn(&Self::Item) -> char + 'a) -> GridRender<'a, Self> {
+        GridRender::new(self, glyph)
+    }
+}
+
+impl<V: MatrixView> RenderGrid for V {}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::matrix::Grid;
+    use crate::utils::string::deformat_string;
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        let grid = "...\n.#.".parse::<Grid<char>>().unwrap();
+        assert_eq!(grid.render().to_string(), "...\n.#.\n");
+
+        let path = [UVec2::new(0, 0), UVec2::new(1, 0), UVec2::new(2, 0), UVec2::new(2, 1)];
+        let rendered = grid.render().cursor(UVec2::new(2, 1), '@').path(path).rulers(true);
+        assert_eq!(rendered.to_string(), "  012\n0 >>v\n1 .#@\n");
+        assert_eq!(grid.render().path(path).glyph_at(UVec2::new(2, 1)), 'v');
+        assert_eq!(grid.render().path_with(path, 'O').highlight([UVec2::new(0, 0)], '*').to_string(), "*OO\n.#O\n");
+    }
+
+    #[test]
+    fn render_with_test() {
+        let matrix = vec![vec![Some(1), None], vec![None, Some(2)]];
+        let rendered = matrix.render_with(|cell| cell.map_or('.', |v| char::from_digit(v, 10).unwrap()));
+        assert_eq!(rendered.to_string(), "1.\n.2\n");
+        assert_eq!(vec![vec![true, false]].render().to_string(), "#.\n");
+        assert_eq!(vec![vec![Some('a'), None]].render().to_string(), "a \n");
+        assert_eq!(matrix.view().skew(1).render_with(|_| 'x').unmapped('-').to_string(), "x-\nxx\n-x\n");
+    }
+
+    #[test]
+    fn rulers_test() {
+        let grid = Grid::filled(12, 11, '.');
+        let rendered = grid.render().cursor(UVec2::new(11, 10), '@').rulers(true).to_string();
+        let expected = deformat_string("
+            |             11
+            |   012345678901
+            | 0 ............
+        ");
+        let expected = expected.lines().map(|line| line.trim_start_matches('|')).collect::<Vec<_>>();
+        let lines = rendered.lines().collect::<Vec<_>>();
+        assert_eq!(lines[..3], expected[..]);
+        assert_eq!(lines[12], "10 ...........@");
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use glam::IVec2;
+use thiserror::Error;
+use crate::utils::matrix::{Grid, MatrixDetails};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SparseGridError {
+    #[error("{pos} is outside of the fixed bounds {min}..{end}")]
+    OutOfBounds { pos: IVec2, min: IVec2, end: IVec2 },
+}
+
+/// `HashMap` backed grid for coordinate driven inputs,
+/// cells that were never set read as the default value and coordinates can go negative
+///
+/// the bounding box grows with every set cell unless fixed bounds are given,
+/// in which case setting anything outside of them is an error
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<IVec2, T>,
+    default: T,
+    bounds: MatrixDetails,
+    fixed: Option<MatrixDetails>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: MatrixDetails::default(),
+            fixed: None,
+        }
+    }
+    pub fn with_fixed_bounds(default: T, fixed: MatrixDetails) -> Self {
+        Self {
+            fixed: Some(fixed),
+            ..Self::new(default)
+        }
+    }
+    /// keeps the cells of a dense grid that differ from `default`, bounds are fixed to the dense grid
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        let fixed = MatrixDetails::from_size(IVec2::ZERO, grid.size());
+        let mut sparse = Self::with_fixed_bounds(default, fixed);
+        for (pos, value) in grid.iter() {
+            if *value != sparse.default {
+                sparse.cells.insert(pos.as_ivec2(), value.clone());
+            }
+        }
+        sparse.bounds = MatrixDetails::from_positions(sparse.cells.keys().copied());
+        sparse
+    }
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+    pub fn fixed_bounds(&self) -> Option<MatrixDetails> {
+        self.fixed
+    }
+    /// smallest bounds containing every set cell
+    pub fn bounds(&self) -> MatrixDetails {
+        self.bounds
+    }
+    /// the fixed bounds if there are any, otherwise the bounding box
+    pub fn extent(&self) -> MatrixDetails {
+        self.fixed.unwrap_or(self.bounds)
+    }
+    /// number of set cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+    /// whether the cell was set, regardless of its value
+    pub fn is_set(&self, pos: IVec2) -> bool {
+        self.cells.contains_key(&pos)
+    }
+    /// the set value, or the default value for anything else
+    pub fn get(&self, pos: IVec2) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+    /// returns the previously set value
+    pub fn set(&mut self, pos: IVec2, value: T) -> Result<Option<T>, SparseGridError> {
+        if let Some(fixed) = self.fixed {
+            if !fixed.contains(pos) {
+                return Err(SparseGridError::OutOfBounds { pos, min: fixed.min(), end: fixed.end() });
+            }
+        }
+        self.bounds = match self.bounds.max_inclusive() {
+            None => MatrixDetails::from_inclusive(pos, pos),
+            Some(max) => MatrixDetails::from_inclusive(self.bounds.min().min(pos), max.max(pos)),
+        };
+        Ok(self.cells.insert(pos, value))
+    }
+    /// unsets a cell, shrinking the bounding box if it was on its edge
+    pub fn remove(&mut self, pos: IVec2) -> Option<T> {
+        let removed = self.cells.remove(&pos)?;
+        let on_edge = pos.cmpeq(self.bounds.min()).any()
+            || self.bounds.max_inclusive().is_some_and(|max| pos.cmpeq(max).any());
+        if on_edge {
+            self.bounds = MatrixDetails::from_positions(self.cells.keys().copied());
+        }
+        Some(removed)
+    }
+    /// set cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.cells.iter().map(|(pos, value)| (*pos, value))
+    }
+    /// dense copy of `bounds`, dense `(0, 0)` is `bounds.min()`
+    pub fn to_grid_within(&self, bounds: MatrixDetails) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(bounds.width(), bounds.height(), |pos| {
+            self.get(bounds.min() + pos.as_ivec2()).clone()
+        })
+    }
+    /// dense copy of the [`SparseGrid::extent`]
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.to_grid_within(self.extent())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::UVec2;
+    use super::*;
+
+    #[test]
+    fn bounds_test() -> Result<(), SparseGridError> {
+        let mut grid = SparseGrid::new('.');
+        assert!(grid.bounds().is_empty());
+        grid.set(IVec2::new(2, 1), '#')?;
+        grid.set(IVec2::new(-1, 3), '#')?;
+        assert_eq!(grid.bounds(), MatrixDetails::from_inclusive(IVec2::new(-1, 1), IVec2::new(2, 3)));
+        assert_eq!(*grid.get(IVec2::new(0, 0)), '.');
+        assert_eq!(*grid.get(IVec2::new(-1, 3)), '#');
+        assert_eq!(grid.len(), 2);
+
+        assert_eq!(grid.remove(IVec2::new(-1, 3)), Some('#'));
+        assert_eq!(grid.bounds(), MatrixDetails::from_inclusive(IVec2::new(2, 1), IVec2::new(2, 1)));
+        assert_eq!(grid.remove(IVec2::new(-1, 3)), None);
+        Ok(())
+    }
+
+    #[test]
+    fn fixed_bounds_test() {
+        let fixed = MatrixDetails::from_size(IVec2::ZERO, UVec2::new(3, 2));
+        let mut grid = SparseGrid::with_fixed_bounds(0, fixed);
+        assert_eq!(grid.set(IVec2::new(1, 1), 5), Ok(None));
+        assert_eq!(grid.set(IVec2::new(1, 1), 6), Ok(Some(5)));
+        assert_eq!(
+            grid.set(IVec2::new(3, 0), 1),
+            Err(SparseGridError::OutOfBounds { pos: IVec2::new(3, 0), min: IVec2::ZERO, end: IVec2::new(3, 2) }),
+        );
+        assert_eq!(grid.extent(), fixed);
+        assert_eq!(grid.to_grid().to_matrix(), vec![vec![0, 0, 0], vec![0, 6, 0]]);
+    }
+
+    #[test]
+    fn to_grid_test() -> Result<(), SparseGridError> {
+        let mut grid = SparseGrid::new('.');
+        grid.set(IVec2::new(-1, -1), 'a')?;
+        grid.set(IVec2::new(1, 0), 'b')?;
+        let dense = grid.to_grid();
+        assert_eq!(dense.to_matrix(), vec![vec!['a', '.', '.'], vec!['.', '.', 'b']]);
+
+        let sparse = SparseGrid::from_grid(&dense, '.');
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(*sparse.get(IVec2::new(2, 1)), 'b');
+        assert_eq!(sparse.fixed_bounds(), Some(MatrixDetails::from_size(IVec2::ZERO, UVec2::new(3, 2))));
+        Ok(())
+    }
+}
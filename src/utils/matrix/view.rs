@@ -0,0 +1,399 @@
+use glam::UVec2;
+use crate::utils::matrix::{Grid, Line, LineKind, Lines, Windows};
+use crate::utils::matrix::windows::window_positions;
+
+/// read only access to a matrix through coordinates,
+/// the adapters remap positions on access so none of them clone or allocate
+///
+/// example:
+///     matrix: AB
+///             CD
+///     matrix.view().rotate_90().flip_horizontal()
+///     result: AC
+///             BD
+pub trait MatrixView {
+    type Item;
+
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    /// `None` outside of the view, and for cells that don't map onto the source i.e. the gaps of a skew
+    fn cell(&self, pos: UVec2) -> Option<&Self::Item>;
+
+    fn size(&self) -> UVec2 {
+        UVec2::new(self.width() as u32, self.height() as u32)
+    }
+    fn contains_pos(&self, pos: UVec2) -> bool {
+        (pos.x as usize) < self.width() && (pos.y as usize) < self.height()
+    }
+    /// borrows the matrix so the adapters don't consume it
+    fn view(&self) -> &Self
+    where Self: Sized
+    {
+        self
+    }
+    /// rotates clockwise by 90 degrees `turns` times, same orientation as [`super::rotate_matrix_n_times`]
+    fn rotate(self, turns: usize) -> Rotate<Self>
+    where Self: Sized
+    {
+        Rotate { inner: self, turns: (turns % 4) as u8 }
+    }
+    fn rotate_90(self) -> Rotate<Self>
+    where Self: Sized
+    {
+        self.rotate(1)
+    }
+    fn rotate_180(self) -> Rotate<Self>
+    where Self: Sized
+    {
+        self.rotate(2)
+    }
+    fn rotate_270(self) -> Rotate<Self>
+    where Self: Sized
+    {
+        self.rotate(3)
+    }
+    /// swaps rows and columns
+    fn transpose(self) -> Transpose<Self>
+    where Self: Sized
+    {
+        Transpose { inner: self }
+    }
+    /// mirrors left to right
+    fn flip_horizontal(self) -> FlipHorizontal<Self>
+    where Self: Sized
+    {
+        FlipHorizontal { inner: self }
+    }
+    /// mirrors top to bottom
+    fn flip_vertical(self) -> FlipVertical<Self>
+    where Self: Sized
+    {
+        FlipVertical { inner: self }
+    }
+    /// `size` cells starting at `min`, clamped to the view
+    fn sub_rect(self, min: UVec2, size: UVec2) -> SubRect<Self>
+    where Self: Sized
+    {
+        let min = min.min(self.size());
+        let size = size.min(self.size() - min);
+        SubRect { inner: self, min, size }
+    }
+    /// same layout as [`super::skew_matrix_by`] but only as tall as the skew needs,
+    /// positive amount skews down, negative amount skews up
+    fn skew(self, amount: i32) -> Skew<Self>
+    where Self: Sized
+    {
+        Skew { inner: self, amount }
+    }
+    /// every mapped cell in row major order
+    fn iter_cells(&self) -> impl Iterator<Item = (UVec2, &Self::Item)>
+    where Self: Sized
+    {
+        let width = self.width();
+        (0..self.width() * self.height())
+            .map(move |ix| UVec2::new((ix % width) as u32, (ix / width) as u32))
+            .filter_map(|pos| Some((pos, self.cell(pos)?)))
+    }
+    /// the `index`th line of a kind, see [`LineKind`] for how lines are ordered
+    fn line(&self, kind: LineKind, index: usize) -> Option<Line<'_, Self>>
+    where Self: Sized
+    {
+        Line::new(self, kind, index)
+    }
+    /// every line of a kind, for scanning rows, columns or diagonals without skewing the matrix first
+    fn lines(&self, kind: LineKind) -> Lines<'_, Self>
+    where Self: Sized
+    {
+        Lines::new(self, kind)
+    }
+    /// every `size` sub view with its top left position, see [`Windows`]
+    fn cell_windows(&self, size: UVec2) -> Windows<'_, Self>
+    where Self: Sized
+    {
+        Windows::new(self, size)
+    }
+    /// convolution style map, the result has one cell per window and cell `pos` comes from the window whose top left is `pos`
+    ///
+    /// example:
+    ///     matrix: 1 2 3
+    ///             4 5 6
+    ///     matrix.map_windows((2, 2), sum)
+    ///     result: 12 16
+    fn map_windows<U>(&self, size: UVec2, mut f: impl FnMut(UVec2, SubRect<&Self>) -> U) -> Grid<U>
+    where Self: Sized
+    {
+        let positions = window_positions(self.size(), size);
+        Grid::from_fn(positions.x as usize, positions.y as usize, |pos| f(pos, self.sub_rect(pos, size)))
+    }
+    /// collects the view, unmapped cells are filled with `T::default()`
+    fn to_matrix(&self) -> Vec<Vec<Self::Item>>
+    where Self::Item: Clone + Default
+    {
+        (0..self.height() as u32)
+            .map(|y| (0..self.width() as u32)
+                .map(|x| self.cell(UVec2::new(x, y)).cloned().unwrap_or_default())
+                .collect()
+            )
+            .collect()
+    }
+}
+
+impl<V: MatrixView + ?Sized> MatrixView for &V {
+    type Item = V::Item;
+
+    fn width(&self) -> usize {
+        (**self).width()
+    }
+    fn height(&self) -> usize {
+        (**self).height()
+    }
+    fn cell(&self, pos: UVec2) -> Option<&Self::Item> {
+        (**self).cell(pos)
+    }
+}
+
+/// the width of the first row, rows are expected to be the same length
+impl<T> MatrixView for Vec<Vec<T>> {
+    type Item = T;
+
+    fn width(&self) -> usize {
+        self.first().map_or(0, Vec::len)
+    }
+    fn height(&self) -> usize {
+        self.len()
+    }
+    fn cell(&self, pos: UVec2) -> Option<&T> {
+        self.get(pos.y as usize)?.get(pos.x as usize)
+    }
+}
+
+impl<T> MatrixView for Grid<T> {
+    type Item = T;
+
+    fn width(&self) -> usize {
+        Grid::width(self)
+    }
+    fn height(&self) -> usize {
+        Grid::height(self)
+    }
+    fn cell(&self, pos: UVec2) -> Option<&T> {
+        self.get(pos)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Rotate<V> {
+    inner: V,
+    turns: u8,
+}
+
+impl<V: MatrixView> MatrixView for Rotate<V> {
+    type Item = V::Item;
+
+    fn width(&self) -> usize {
+        if self.turns.is_multiple_of(2) { self.inner.width() } else { self.inner.height() }
+    }
+    fn height(&self) -> usize {
+        if self.turns.is_multiple_of(2) { self.inner.height() } else { self.inner.width() }
+    }
+    fn cell(&self, pos: UVec2) -> Option<&Self::Item> {
+        if !self.contains_pos(pos) {
+            return None;
+        }
+        let max = self.inner.size() - UVec2::ONE;
+        let pos = match self.turns {
+            0 => pos,
+            1 => UVec2::new(pos.y, max.y - pos.x),
+            2 => max - pos,
+            _ => UVec2::new(max.x - pos.y, pos.x),
+        };
+        self.inner.cell(pos)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Transpose<V> {
+    inner: V,
+}
+
+impl<V: MatrixView> MatrixView for Transpose<V> {
+    type Item = V::Item;
+
+    fn width(&self) -> usize {
+        self.inner.height()
+    }
+    fn height(&self) -> usize {
+        self.inner.width()
+    }
+    fn cell(&self, pos: UVec2) -> Option<&Self::Item> {
+        self.inner.cell(UVec2::new(pos.y, pos.x))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct FlipHorizontal<V> {
+    inner: V,
+}
+
+impl<V: MatrixView> MatrixView for FlipHorizontal<V> {
+    type Item = V::Item;
+
+    fn width(&self) -> usize {
+        self.inner.width()
+    }
+    fn height(&self) -> usize {
+        self.inner.height()
+    }
+    fn cell(&self, pos: UVec2) -> Option<&Self::Item> {
+        if !self.contains_pos(pos) {
+            return None;
+        }
+        self.inner.cell(UVec2::new(self.width() as u32 - 1 - pos.x, pos.y))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct FlipVertical<V> {
+    inner: V,
+}
+
+impl<V: MatrixView> MatrixView for FlipVertical<V> {
+    type Item = V::Item;
+
+    fn width(&self) -> usize {
+        self.inner.width()
+    }
+    fn height(&self) -> usize {
+        self.inner.height()
+    }
+    fn cell(&self, pos: UVec2) -> Option<&Self::Item> {
+        if !self.contains_pos(pos) {
+            return None;
+        }
+        self.inner.cell(UVec2::new(pos.x, self.height() as u32 - 1 - pos.y))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SubRect<V> {
+    inner: V,
+    min: UVec2,
+    size: UVec2,
+}
+
+impl<V: MatrixView> MatrixView for SubRect<V> {
+    type Item = V::Item;
+
+    fn width(&self) -> usize {
+        self.size.x as usize
+    }
+    fn height(&self) -> usize {
+        self.size.y as usize
+    }
+    fn cell(&self, pos: UVec2) -> Option<&Self::Item> {
+        if !self.contains_pos(pos) {
+            return None;
+        }
+        self.inner.cell(self.min + pos)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Skew<V> {
+    inner: V,
+    amount: i32,
+}
+
+impl<V: MatrixView> MatrixView for Skew<V> {
+    type Item = V::Item;
+
+    fn width(&self) -> usize {
+        self.inner.width()
+    }
+    fn height(&self) -> usize {
+        if self.inner.width() == 0 {
+            return self.inner.height();
+        }
+        self.inner.height() + (self.inner.width() - 1) * self.amount.unsigned_abs() as usize
+    }
+    fn cell(&self, pos: UVec2) -> Option<&Self::Item> {
+        if !self.contains_pos(pos) {
+            return None;
+        }
+        let offset = pos.x * self.amount.unsigned_abs();
+        let y = if self.amount >= 0 {
+            pos.y.checked_sub(offset)?
+        } else {
+            (self.height() as u32 - 1 - pos.y).checked_sub(offset)?
+        };
+        self.inner.cell(UVec2::new(pos.x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::matrix::{rotate_matrix_n_times, skew_matrix_by};
+    use super::*;
+
+    fn matrix() -> Vec<Vec<i32>> {
+        vec![vec![1, 2, 3], vec![4, 5, 6]]
+    }
+
+    #[test]
+    fn rotate_test() {
+        let matrix = matrix();
+        for turns in 0..6 {
+            assert_eq!(matrix.view().rotate(turns).to_matrix(), rotate_matrix_n_times(&matrix, turns), "{turns} turns");
+        }
+        assert_eq!(matrix.view().rotate_90().cell(UVec2::new(0, 0)), Some(&4));
+        assert_eq!(matrix.view().rotate_90().cell(UVec2::new(2, 0)), None);
+    }
+
+    #[test]
+    fn transpose_flip_test() {
+        let matrix = matrix();
+        assert_eq!(matrix.view().transpose().to_matrix(), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert_eq!(matrix.view().flip_horizontal().to_matrix(), vec![vec![3, 2, 1], vec![6, 5, 4]]);
+        assert_eq!(matrix.view().flip_vertical().to_matrix(), vec![vec![4, 5, 6], vec![1, 2, 3]]);
+        assert_eq!(
+            matrix.view().rotate_90().flip_horizontal().to_matrix(),
+            matrix.view().transpose().to_matrix(),
+        );
+        assert_eq!(matrix.view().flip_horizontal().cell(UVec2::new(3, 0)), None);
+    }
+
+    #[test]
+    fn sub_rect_test() {
+        let matrix = matrix();
+        let sub = matrix.view().sub_rect(UVec2::new(1, 0), UVec2::new(5, 1));
+        assert_eq!(sub.size(), UVec2::new(2, 1));
+        assert_eq!(sub.to_matrix(), vec![vec![2, 3]]);
+        assert_eq!(sub.cell(UVec2::new(0, 1)), None);
+        assert_eq!(matrix.view().sub_rect(UVec2::new(4, 4), UVec2::ONE).size(), UVec2::ZERO);
+    }
+
+    #[test]
+    fn skew_test() {
+        let matrix = vec![vec![1, 2, 3]];
+        assert_eq!(matrix.view().skew(1).to_matrix(), skew_matrix_by(&matrix, 1));
+        assert_eq!(matrix.view().skew(-1).to_matrix(), skew_matrix_by(&matrix, -1));
+        assert_eq!(matrix.view().skew(1).cell(UVec2::new(1, 0)), None);
+        assert_eq!(matrix.view().skew(1).iter_cells().map(|(_, value)| *value).collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let matrix = self::matrix();
+        assert_eq!(matrix.view().skew(2).to_matrix(), vec![
+            vec![1, 0, 0],
+            vec![4, 0, 0],
+            vec![0, 2, 0],
+            vec![0, 5, 0],
+            vec![0, 0, 3],
+            vec![0, 0, 6],
+        ]);
+    }
+
+    #[test]
+    fn grid_test() {
+        let grid = Grid::from_matrix(&matrix()).unwrap();
+        assert_eq!(grid.view().rotate_180().to_matrix(), vec![vec![6, 5, 4], vec![3, 2, 1]]);
+    }
+}
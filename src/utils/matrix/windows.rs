@@ -0,0 +1,140 @@
+use std::iter::FusedIterator;
+use glam::UVec2;
+use crate::utils::matrix::{MatrixView, SubRect};
+
+/// every `size` window that fully fits in a view, in row major order of their top left corner
+///
+/// example:
+///     matrix: ABC
+///             DEF
+///     windows of 2x2: (0, 0) AB/DE, (1, 0) BC/EF
+#[derive(Debug)]
+pub struct Windows<'a, V> {
+    view: &'a V,
+    size: UVec2,
+    /// number of window positions per row
+    columns: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<V> Clone for Windows<'_, V> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, V: MatrixView> Windows<'a, V> {
+    /// no windows for a zero sized window or one larger than the view
+    pub fn new(view: &'a V, size: UVec2) -> Self {
+        let positions = window_positions(view.size(), size);
+        Self {
+            view,
+            size,
+            columns: positions.x as usize,
+            front: 0,
+            back: (positions.x * positions.y) as usize,
+        }
+    }
+    fn window(&self, index: usize) -> (UVec2, SubRect<&'a V>) {
+        let pos = UVec2::new((index % self.columns) as u32, (index / self.columns) as u32);
+        (pos, MatrixView::sub_rect(self.view, pos, self.size))
+    }
+}
+
+impl<'a, V: MatrixView> Iterator for Windows<'a, V> {
+    type Item = (UVec2, SubRect<&'a V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.window(self.front - 1))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+}
+
+impl<V: MatrixView> DoubleEndedIterator for Windows<'_, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.window(self.back))
+    }
+}
+
+impl<V: MatrixView> ExactSizeIterator for Windows<'_, V> {}
+
+impl<V: MatrixView> FusedIterator for Windows<'_, V> {}
+
+/// how many window positions fit along each axis
+pub(super) fn window_positions(view: UVec2, window: UVec2) -> UVec2 {
+    if window.cmpeq(UVec2::ZERO).any() || window.cmpgt(view).any() {
+        return UVec2::ZERO;
+    }
+    view - window + UVec2::ONE
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::matrix::Grid;
+    use crate::utils::string::deformat_string;
+    use super::*;
+
+    #[test]
+    fn windows_test() {
+        let grid = "ABC\nDEF".parse::<Grid<char>>().unwrap();
+        let windows = grid
+            .cell_windows(UVec2::splat(2))
+            .map(|(pos, window)| (pos, window.to_matrix()))
+            .collect::<Vec<_>>();
+        assert_eq!(windows, vec![
+            (UVec2::new(0, 0), vec![vec!['A', 'B'], vec!['D', 'E']]),
+            (UVec2::new(1, 0), vec![vec!['B', 'C'], vec!['E', 'F']]),
+        ]);
+
+        let mut rows = grid.cell_windows(UVec2::new(3, 1));
+        assert_eq!(rows.len(), 2);
+        let (pos, last) = rows.next_back().unwrap();
+        assert_eq!(pos, UVec2::new(0, 1));
+        assert_eq!(last.cell(UVec2::new(2, 0)), Some(&'F'));
+        assert_eq!(grid.cell_windows(UVec2::new(1, 3)).len(), 0);
+        assert_eq!(grid.cell_windows(UVec2::ZERO).len(), 0);
+    }
+
+    #[test]
+    fn map_windows_test() {
+        let matrix = vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10, 11, 12]];
+        let sums = matrix.map_windows(UVec2::splat(2), |_, window| window.iter_cells().map(|(_, v)| v).sum::<i32>());
+        assert_eq!(sums.to_matrix(), vec![vec![14, 18, 22], vec![30, 34, 38]]);
+        let origins = matrix.map_windows(UVec2::new(4, 2), |pos, _| pos);
+        assert_eq!(origins.to_matrix(), vec![vec![UVec2::new(0, 0)], vec![UVec2::new(0, 1)]]);
+        assert!(matrix.map_windows(UVec2::new(5, 1), |_, _| ()).is_empty());
+    }
+
+    #[test]
+    fn x_mas_test() {
+        let grid = deformat_string("
+            MMMSXXMASM
+            MSAMXMSMSA
+            AMXSXMAAMM
+            MSAMASMSMX
+            XMASAMXAMM
+            XXAMMXXAMA
+            SMSMSASXSS
+            SAXAMASAAA
+            MAMMMXMMMM
+            MXMXAXMASX
+        ").parse::<Grid<char>>().unwrap();
+        let is_mas = |a: Option<&char>, b: Option<&char>| matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')));
+        let x_mas = grid.map_windows(UVec2::splat(3), |_, window| {
+            let at = |x, y| window.cell(UVec2::new(x, y));
+            at(1, 1) == Some(&'A') && is_mas(at(0, 0), at(2, 2)) && is_mas(at(2, 0), at(0, 2))
+        });
+        assert_eq!(x_mas.cells().iter().filter(|&&found| found).count(), 9);
+    }
+}
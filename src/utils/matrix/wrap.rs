@@ -0,0 +1,192 @@
+use glam::{I64Vec2, IVec2, UVec2};
+use crate::utils::matrix::{Grid, MatrixDetails};
+
+const NEIGHBORS: [IVec2; 4] = [IVec2::NEG_Y, IVec2::X, IVec2::Y, IVec2::NEG_X];
+const NEIGHBORS_DIAGONAL: [IVec2; 8] = [
+    IVec2::new(-1, -1),
+    IVec2::NEG_Y,
+    IVec2::new(1, -1),
+    IVec2::X,
+    IVec2::new(1, 1),
+    IVec2::Y,
+    IVec2::new(-1, 1),
+    IVec2::NEG_X,
+];
+
+/// wrap-around addressing for bounds whose edges connect,
+/// stepping off one side comes back in on the opposite side
+///
+/// example:
+///     bounds: (0, 0)..(5, 3)
+///     wrap((5, -1)) -> (0, 2)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Torus {
+    bounds: MatrixDetails,
+}
+
+impl Torus {
+    /// panics for empty bounds since nothing can wrap into them
+    pub fn new(bounds: MatrixDetails) -> Self {
+        if bounds.is_empty() {
+            panic!("Invalid matrix dimensions");
+        }
+        Self { bounds }
+    }
+    pub fn from_size(size: UVec2) -> Self {
+        Self::new(MatrixDetails::from_size(IVec2::ZERO, size))
+    }
+    pub fn bounds(&self) -> MatrixDetails {
+        self.bounds
+    }
+    pub fn size(&self) -> UVec2 {
+        self.bounds.size()
+    }
+    /// moves any position into the bounds
+    pub fn wrap(&self, pos: IVec2) -> IVec2 {
+        let size = self.size().as_i64vec2();
+        let min = self.bounds.min().as_i64vec2();
+        (min + (pos.as_i64vec2() - min).rem_euclid(size)).as_ivec2()
+    }
+    pub fn step(&self, pos: IVec2, delta: IVec2) -> IVec2 {
+        self.advance(pos, delta, 1)
+    }
+    /// position after moving by `velocity` `steps` times, computed directly and without overflowing
+    pub fn advance(&self, pos: IVec2, velocity: IVec2, steps: u64) -> IVec2 {
+        let size = self.size().as_i64vec2();
+        let min = self.bounds.min().as_i64vec2();
+        let offset = (pos.as_i64vec2() - min).rem_euclid(size);
+        let velocity = velocity.as_i64vec2().rem_euclid(size);
+        let steps = I64Vec2::new((steps % size.x as u64) as i64, (steps % size.y as u64) as i64);
+        let offset = (offset + velocity * steps).rem_euclid(size);
+        (min + offset).as_ivec2()
+    }
+    /// up, right, down and left, wrapped
+    pub fn neighbors(&self, pos: IVec2) -> [IVec2; 4] {
+        NEIGHBORS.map(|delta| self.step(pos, delta))
+    }
+    /// all eight surrounding positions clockwise from the top left, wrapped
+    pub fn neighbors_diagonal(&self, pos: IVec2) -> [IVec2; 8] {
+        NEIGHBORS_DIAGONAL.map(|delta| self.step(pos, delta))
+    }
+    pub fn index_from_pos(&self, pos: IVec2) -> usize {
+        self.bounds.index_from_pos(self.wrap(pos)).expect("wrapped position is within bounds")
+    }
+    /// 0 top left, 1 top right, 2 bottom left, 3 bottom right,
+    /// `None` for positions on the middle row or column of an odd sized axis
+    pub fn quadrant(&self, pos: IVec2) -> Option<usize> {
+        let offset = (self.wrap(pos) - self.bounds.min()).as_uvec2();
+        let size = self.size();
+        let half = |offset: u32, size: u32| {
+            if offset < size / 2 {
+                Some(0)
+            } else if offset >= size.div_ceil(2) {
+                Some(1)
+            } else {
+                None
+            }
+        };
+        Some(half(offset.x, size.x)? + half(offset.y, size.y)? * 2)
+    }
+    /// how many positions fall into each quadrant, see [`Torus::quadrant`]
+    pub fn count_quadrants(&self, positions: impl IntoIterator<Item = IVec2>) -> [usize; 4] {
+        let mut counts = [0; 4];
+        for quadrant in positions.into_iter().filter_map(|pos| self.quadrant(pos)) {
+            counts[quadrant] += 1;
+        }
+        counts
+    }
+    /// which of `segments.x` by `segments.y` evenly split segments a position falls into
+    pub fn segment(&self, pos: IVec2, segments: UVec2) -> UVec2 {
+        let segments = segments.clamp(UVec2::ONE, self.size());
+        let offset = (self.wrap(pos) - self.bounds.min()).as_u64vec2();
+        (offset * segments.as_u64vec2() / self.size().as_u64vec2()).as_uvec2()
+    }
+    /// how many positions fall into each segment, see [`Torus::segment`]
+    pub fn count_segments(&self, positions: impl IntoIterator<Item = IVec2>, segments: UVec2) -> Grid<usize> {
+        let segments = segments.clamp(UVec2::ONE, self.size());
+        let mut counts = Grid::filled(segments.x as usize, segments.y as usize, 0);
+        for pos in positions {
+            counts[self.segment(pos, segments)] += 1;
+        }
+        counts
+    }
+}
+
+impl<T> Grid<T> {
+    /// reads the grid as if its edges wrapped, `None` only for empty grids
+    pub fn get_wrapped(&self, pos: IVec2) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.get(Torus::from_size(self.size()).wrap(pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_test() {
+        let torus = Torus::from_size(UVec2::new(5, 3));
+        assert_eq!(torus.wrap(IVec2::new(5, -1)), IVec2::new(0, 2));
+        assert_eq!(torus.wrap(IVec2::new(-11, 7)), IVec2::new(4, 1));
+        assert_eq!(torus.step(IVec2::new(0, 0), IVec2::NEG_X), IVec2::new(4, 0));
+        assert_eq!(torus.index_from_pos(IVec2::new(-1, -1)), 14);
+        assert_eq!(
+            torus.neighbors(IVec2::ZERO),
+            [IVec2::new(0, 2), IVec2::new(1, 0), IVec2::new(0, 1), IVec2::new(4, 0)],
+        );
+        assert_eq!(torus.neighbors_diagonal(IVec2::ZERO)[0], IVec2::new(4, 2));
+
+        let offset = Torus::new(MatrixDetails::from_size(IVec2::new(-2, 10), UVec2::new(4, 4)));
+        assert_eq!(offset.wrap(IVec2::new(2, 9)), IVec2::new(-2, 13));
+        assert_eq!(offset.wrap(IVec2::new(i32::MAX, i32::MIN)), IVec2::new(-1, 12));
+    }
+
+    #[test]
+    fn advance_test() {
+        let torus = Torus::from_size(UVec2::new(11, 7));
+        let mut pos = IVec2::new(2, 4);
+        let velocity = IVec2::new(2, -3);
+        for _ in 0..5 {
+            pos = torus.step(pos, velocity);
+        }
+        assert_eq!(pos, IVec2::new(1, 3));
+        assert_eq!(torus.advance(IVec2::new(2, 4), velocity, 5), pos);
+        assert_eq!(torus.advance(IVec2::new(2, 4), velocity, 77 * 1_000_000_000), IVec2::new(2, 4));
+        assert_eq!(torus.advance(IVec2::ZERO, IVec2::new(i32::MAX, i32::MIN), u64::MAX), IVec2::new(4, 5));
+        let offset = Torus::new(MatrixDetails::from_size(IVec2::new(-2, 10), UVec2::new(4, 4)));
+        assert_eq!(offset.advance(IVec2::new(i32::MAX, i32::MIN), IVec2::ONE, 1), IVec2::new(0, 13));
+    }
+
+    #[test]
+    fn quadrant_test() {
+        let torus = Torus::from_size(UVec2::new(11, 7));
+        assert_eq!(torus.quadrant(IVec2::new(0, 0)), Some(0));
+        assert_eq!(torus.quadrant(IVec2::new(6, 2)), Some(1));
+        assert_eq!(torus.quadrant(IVec2::new(4, 4)), Some(2));
+        assert_eq!(torus.quadrant(IVec2::new(10, 6)), Some(3));
+        assert_eq!(torus.quadrant(IVec2::new(5, 0)), None);
+        assert_eq!(torus.quadrant(IVec2::new(0, 3)), None);
+        assert_eq!(
+            torus.count_quadrants([IVec2::new(0, 0), IVec2::new(1, 1), IVec2::new(5, 5), IVec2::new(-1, -1)]),
+            [2, 0, 0, 1],
+        );
+
+        let even = Torus::from_size(UVec2::new(4, 4));
+        assert_eq!(even.quadrant(IVec2::new(2, 1)), Some(1));
+    }
+
+    #[test]
+    fn segment_test() {
+        let torus = Torus::from_size(UVec2::new(10, 4));
+        assert_eq!(torus.segment(IVec2::new(9, 3), UVec2::new(3, 2)), UVec2::new(2, 1));
+        assert_eq!(torus.segment(IVec2::new(3, 1), UVec2::new(3, 2)), UVec2::new(0, 0));
+        let counts = torus.count_segments([IVec2::new(0, 0), IVec2::new(9, 3), IVec2::new(-1, -1)], UVec2::new(2, 2));
+        assert_eq!(counts.to_matrix(), vec![vec![1, 0], vec![0, 2]]);
+
+        let grid = Grid::from_matrix(&[vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(grid.get_wrapped(IVec2::new(-1, 2)), Some(&2));
+    }
+}
@@ -1,3 +1,5 @@
+pub mod export;
 pub mod matrix;
+pub mod recorder;
 pub mod string;
 pub mod vec2;
@@ -0,0 +1,452 @@
+use std::fmt::Display;
+use std::io::{IsTerminal, Read, Write};
+use std::iter::Peekable;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+use serde_json::json;
+
+const CLEAR: &str = "\x1b[H\x1b[2J";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+const MIN_FPS: f64 = 0.25;
+const MAX_FPS: f64 = 240.0;
+
+/// a rendered step of a simulation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// simulation step the frame was taken at, counting every [`Recorder::record`] call
+    pub step: usize,
+    pub text: String,
+}
+
+/// collects one frame per simulation step for [`play`] or [`Recorder::to_asciicast`]
+///
+/// example:
+///     let mut recorder = Recorder::new().every(10);
+///     while walker.step() {
+///         recorder.record_with(|| board.render().cursor(walker.pos, '^').to_string());
+///     }
+///     recorder.record_final(board.render());
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recorder {
+    frames: Vec<Frame>,
+    /// number of steps seen so far
+    steps: usize,
+    every: usize,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Self { every: 1, ..Default::default() }
+    }
+    /// only keeps every `n`th step, long simulations on big grids take a lot of memory otherwise
+    pub fn every(mut self, n: usize) -> Self {
+        self.every = n.max(1);
+        self
+    }
+    /// counts a step and keeps its frame if it falls on the interval, the first step is always kept
+    pub fn record(&mut self, frame: impl Display) {
+        self.record_with(|| frame);
+    }
+    /// same as [`Recorder::record`] but only builds the frame for steps that are kept
+    pub fn record_with<D: Display>(&mut self, frame: impl FnOnce() -> D) {
+        let step = self.steps;
+        self.steps += 1;
+        if step.is_multiple_of(self.every) {
+            self.frames.push(Frame { step, text: frame().to_string() });
+        }
+    }
+    /// keeps the frame regardless of the interval, for the final state of a simulation
+    pub fn record_final(&mut self, frame: impl Display) {
+        let step = self.steps;
+        self.steps += 1;
+        self.frames.push(Frame { step, text: frame.to_string() });
+    }
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+    /// number of kept frames
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+    /// number of recorded steps, kept or not
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+    /// asciinema v2 cast, one event per frame `1 / fps` seconds apart, play it with `asciinema play`
+    pub fn to_asciicast(&self, fps: f64) -> String {
+        let fps = fps.clamp(MIN_FPS, MAX_FPS);
+        let lines = || self.frames.iter().flat_map(|frame| frame.text.lines());
+        let width = lines().map(|line| line.chars().count()).max().unwrap_or(0);
+        let height = self.frames.iter().map(|frame| frame.text.lines().count()).max().unwrap_or(0);
+        let header = json!({ "version": 2, "width": width.max(1), "height": height.max(1) });
+        let mut cast = header.to_string() + "\n";
+        for (ix, frame) in self.frames.iter().enumerate() {
+            let text = format!("{CLEAR}{}", frame.text.replace('\n', "\r\n"));
+            cast += &json!([ix as f64 / fps, "o", text]).to_string();
+            cast.push('\n');
+        }
+        cast
+    }
+    pub fn write_asciicast(&self, path: impl AsRef<Path>, fps: f64) -> std::io::Result<()> {
+        std::fs::write(path, self.to_asciicast(fps))
+    }
+}
+
+/// playback controls
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// space
+    TogglePause,
+    /// `n` or `.`, pauses
+    Next,
+    /// `p` or `,`, pauses
+    Previous,
+    /// `+` or `=`, doubles the speed
+    Faster,
+    /// `-`, halves the speed
+    Slower,
+    /// `g`
+    First,
+    /// `G`
+    Last,
+    /// `q`
+    Quit,
+}
+
+impl Key {
+    pub fn from_byte(byte: u8) -> Option<Key> {
+        match byte {
+            b' ' => Some(Key::TogglePause),
+            b'n' | b'.' => Some(Key::Next),
+            b'p' | b',' => Some(Key::Previous),
+            b'+' | b'=' => Some(Key::Faster),
+            b'-' => Some(Key::Slower),
+            b'g' => Some(Key::First),
+            b'G' => Some(Key::Last),
+            b'q' => Some(Key::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// which frame is shown and how fast playback moves on, independent of the terminal
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Playback {
+    index: usize,
+    len: usize,
+    fps: f64,
+    paused: bool,
+}
+
+impl Playback {
+    pub fn new(len: usize, fps: f64) -> Self {
+        Self { index: 0, len, fps: fps.clamp(MIN_FPS, MAX_FPS), paused: false }
+    }
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+    pub fn frame_duration(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+    /// `false` once playback should stop
+    pub fn handle(&mut self, key: Key) -> bool {
+        let last = self.len.saturating_sub(1);
+        match key {
+            Key::TogglePause => self.paused = !self.paused,
+            Key::Next => {
+                self.paused = true;
+                self.index = (self.index + 1).min(last);
+            }
+            Key::Previous => {
+                self.paused = true;
+                self.index = self.index.saturating_sub(1);
+            }
+            Key::Faster => self.fps = (self.fps * 2.0).min(MAX_FPS),
+            Key::Slower => self.fps = (self.fps / 2.0).max(MIN_FPS),
+            Key::First => self.index = 0,
+            Key::Last => self.index = last,
+            Key::Quit => return false,
+        }
+        true
+    }
+    /// moves to the next frame unless paused, `false` when there is no next frame
+    pub fn tick(&mut self) -> bool {
+        if self.paused {
+            return true;
+        }
+        if self.index + 1 >= self.len {
+            return false;
+        }
+        self.index += 1;
+        true
+    }
+    /// keeps the last frame on screen so it can still be stepped through
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+}
+
+/// plays frames into `out` while reading controls from `keys`,
+/// without keys (the sender is dropped) playback stops after the last frame
+pub fn play_with(frames: &[Frame], fps: f64, out: &mut impl Write, keys: &Receiver<Key>) -> std::io::Result<()> {
+    let mut playback = Playback::new(frames.len(), fps);
+    let mut interactive = true;
+    while let Some(frame) = frames.get(playback.index()) {
+        let state = if playback.is_paused() { "paused" } else { "playing" };
+        write!(out, "{CLEAR}{}", frame.text)?;
+        writeln!(
+            out,
+            "frame {}/{} step {} | {} fps {state} | space pause, n/p step, +/- speed, g/G first/last, q quit",
+            playback.index() + 1,
+            frames.len(),
+            frame.step,
+            playback.fps(),
+        )?;
+        out.flush()?;
+
+        let key = if !interactive {
+            std::thread::sleep(playback.frame_duration());
+            None
+        } else if playback.is_paused() {
+            match keys.recv() {
+                Ok(key) => Some(key),
+                Err(_) => return Ok(()),
+            }
+        } else {
+            match keys.recv_timeout(playback.frame_duration()) {
+                Ok(key) => Some(key),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => {
+                    interactive = false;
+                    None
+                }
+            }
+        };
+        let running = match key {
+            Some(key) => playback.handle(key),
+            None => playback.tick(),
+        };
+        if !running {
+            if key.is_some() || !interactive {
+                break;
+            }
+            playback.pause();
+        }
+    }
+    Ok(())
+}
+
+/// puts the terminal into unbuffered no echo mode until dropped
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    fn enable() -> Option<Self> {
+        let saved = Command::new("stty").arg("-g").stdin(Stdio::inherit()).output().ok()?;
+        let saved = String::from_utf8(saved.stdout).ok()?.trim().to_string();
+        let status = Command::new("stty").args(["-icanon", "-echo", "min", "1"]).stdin(Stdio::inherit()).status().ok()?;
+        status.success().then_some(Self { saved })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = Command::new("stty").arg(&self.saved).stdin(Stdio::inherit()).status();
+    }
+}
+
+/// plays frames in the terminal, keys only work when stdin is a terminal with `stty` available
+pub fn play(frames: &[Frame], fps: f64) -> std::io::Result<()> {
+    let (sender, keys) = mpsc::channel();
+    let raw_mode = if std::io::stdin().is_terminal() { RawMode::enable() } else { None };
+    if raw_mode.is_some() {
+        std::thread::spawn(move || {
+            for byte in std::io::stdin().lock().bytes() {
+                let Ok(byte) = byte else { break };
+                if let Some(key) = Key::from_byte(byte) {
+                    if sender.send(key).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+    } else {
+        drop(sender);
+    }
+    let mut out = std::io::stdout().lock();
+    write!(out, "{HIDE_CURSOR}")?;
+    let played = play_with(frames, fps, &mut out, &keys);
+    write!(out, "{SHOW_CURSOR}")?;
+    out.flush()?;
+    drop(raw_mode);
+    played
+}
+
+/// what a simulation binary should do with its recording, picked from the command line
+///
+/// `--play [fps]` plays in the terminal, `--cast <path> [fps]` writes an asciinema cast
+#[derive(Debug, Clone, PartialEq)]
+pub enum Replay {
+    Play { fps: f64 },
+    Cast { path: PathBuf, fps: f64 },
+}
+
+impl Replay {
+    pub const DEFAULT_FPS: f64 = 10.0;
+
+    /// `None` when neither flag was given
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Option<Self> {
+        let mut args = args.into_iter().peekable();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--play" => return Some(Replay::Play { fps: Self::fps_arg(&mut args) }),
+                "--cast" => {
+                    let path = PathBuf::from(args.next()?);
+                    return Some(Replay::Cast { path, fps: Self::fps_arg(&mut args) });
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+    /// takes the next argument if it is a number
+    fn fps_arg(args: &mut Peekable<impl Iterator<Item = String>>) -> f64 {
+        match args.peek().and_then(|next| next.parse::<f64>().ok()) {
+            Some(fps) => {
+                args.next();
+                fps
+            }
+            None => Self::DEFAULT_FPS,
+        }
+    }
+    pub fn run(&self, recorder: &Recorder) -> std::io::Result<()> {
+        match self {
+            Replay::Play { fps } => play(recorder.frames(), *fps),
+            Replay::Cast { path, fps } => recorder.write_asciicast(path, *fps),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorder() -> Recorder {
+        let mut recorder = Recorder::new();
+        for text in ["a\n", "b\n", "c\n"] {
+            recorder.record(text);
+        }
+        recorder
+    }
+
+    #[test]
+    fn record_test() {
+        let mut recorder = Recorder::new().every(2);
+        for step in 0..5 {
+            recorder.record(step);
+        }
+        recorder.record_final("done");
+        assert_eq!(recorder.steps(), 6);
+
+        let mut rendered = 0;
+        let mut lazy = Recorder::new().every(3);
+        for step in 0..7 {
+            lazy.record_with(|| {
+                rendered += 1;
+                step
+            });
+        }
+        assert_eq!(rendered, 3);
+        assert_eq!(lazy.len(), 3);
+        assert_eq!(
+            recorder.frames().iter().map(|frame| (frame.step, frame.text.as_str())).collect::<Vec<_>>(),
+            vec![(0, "0"), (2, "2"), (4, "4"), (5, "done")],
+        );
+    }
+
+    #[test]
+    fn asciicast_test() {
+        let cast = recorder().to_asciicast(2.0);
+        let lines = cast.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        let header = serde_json::from_str::<serde_json::Value>(lines[0]).unwrap();
+        assert_eq!(header, json!({ "version": 2, "width": 1, "height": 1 }));
+        let event = serde_json::from_str::<serde_json::Value>(lines[2]).unwrap();
+        assert_eq!(event, json!([0.5, "o", "\u{1b}[H\u{1b}[2Jb\r\n"]));
+    }
+
+    #[test]
+    fn playback_test() {
+        let mut playback = Playback::new(3, 10.0);
+        assert!(playback.tick());
+        assert_eq!(playback.index(), 1);
+        assert!(playback.handle(Key::TogglePause));
+        assert!(playback.tick());
+        assert_eq!(playback.index(), 1);
+        playback.handle(Key::Next);
+        playback.handle(Key::Next);
+        assert_eq!(playback.index(), 2);
+        playback.handle(Key::Previous);
+        assert_eq!(playback.index(), 1);
+        playback.handle(Key::Faster);
+        assert_eq!(playback.fps(), 20.0);
+        playback.handle(Key::Last);
+        playback.handle(Key::TogglePause);
+        assert!(!playback.tick());
+        assert!(!playback.handle(Key::Quit));
+        assert_eq!(Key::from_byte(b' '), Some(Key::TogglePause));
+        assert_eq!(Key::from_byte(b'x'), None);
+    }
+
+    #[test]
+    fn play_with_test() -> std::io::Result<()> {
+        let frames = recorder().frames().to_vec();
+
+        // without keys every frame is shown once
+        let (sender, keys) = mpsc::channel();
+        drop(sender);
+        let mut out = vec![];
+        play_with(&frames, MAX_FPS, &mut out, &keys)?;
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches(CLEAR).count(), 3);
+        assert!(out.contains("c\nframe 3/3 step 2"));
+
+        // pause, step back, quit
+        let (sender, keys) = mpsc::channel();
+        for key in [Key::TogglePause, Key::Previous, Key::Quit] {
+            sender.send(key).unwrap();
+        }
+        let mut out = vec![];
+        play_with(&frames, 1.0, &mut out, &keys)?;
+        let out = String::from_utf8(out).unwrap();
+        let shown = out.split(CLEAR).skip(1).map(|screen| &screen[..1]).collect::<String>();
+        assert_eq!(shown, "aaa");
+        Ok(())
+    }
+
+    #[test]
+    fn replay_from_args_test() {
+        let args = |args: &[&str]| Replay::from_args(args.iter().map(|arg| arg.to_string()));
+        assert_eq!(args(&[]), None);
+        assert_eq!(args(&["--play"]), Some(Replay::Play { fps: Replay::DEFAULT_FPS }));
+        assert_eq!(args(&["--play", "30"]), Some(Replay::Play { fps: 30.0 }));
+        assert_eq!(
+            args(&["--cast", "walk.cast", "5"]),
+            Some(Replay::Cast { path: PathBuf::from("walk.cast"), fps: 5.0 }),
+        );
+        assert_eq!(args(&["--cast"]), None);
+    }
+}